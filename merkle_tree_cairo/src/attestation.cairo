@@ -0,0 +1,28 @@
+use core::ecdsa::check_ecdsa_signature;
+use core::hash::LegacyHash;
+
+/// Hash signed by `RootAttestation` in Rust:
+/// `compute_hash_on_elements([campaign_id, wave, root, input_digest])`.
+pub fn attestation_hash(
+    campaign_id: felt252, wave: u64, root: felt252, input_digest: felt252
+) -> felt252 {
+    let hash = LegacyHash::hash(0, campaign_id);
+    let hash = LegacyHash::hash(hash, wave.into());
+    let hash = LegacyHash::hash(hash, root);
+    let hash = LegacyHash::hash(hash, input_digest);
+    LegacyHash::hash(hash, 4)
+}
+
+/// Checks that `signer`, a Stark public key, signed a root for a campaign wave.
+pub fn verify_attestation(
+    signer: felt252,
+    campaign_id: felt252,
+    wave: u64,
+    root: felt252,
+    input_digest: felt252,
+    signature_r: felt252,
+    signature_s: felt252
+) -> bool {
+    let message_hash = attestation_hash(campaign_id, wave, root, input_digest);
+    check_ecdsa_signature(message_hash, signer, signature_r, signature_s)
+}
@@ -13,6 +13,22 @@ pub trait IClaimer<TContractState> {
     fn set_merkle_root(ref self: TContractState, root: felt252);
 
     fn get_merkle_root(ref self: TContractState) -> felt252;
+
+    fn set_root_signer(ref self: TContractState, signer: felt252);
+
+    fn get_root_signer(ref self: TContractState) -> felt252;
+
+    fn get_last_wave(ref self: TContractState) -> u64;
+
+    fn set_attested_merkle_root(
+        ref self: TContractState,
+        root: felt252,
+        campaign_id: felt252,
+        wave: u64,
+        input_digest: felt252,
+        signature_r: felt252,
+        signature_s: felt252
+    );
 }
 
 #[starknet::contract]
@@ -22,6 +38,7 @@ pub mod Claimer {
         Hasher, MerkleTree, MerkleTreeImpl, pedersen::PedersenHasherImpl, MerkleTreeTrait,
     };
     use core::hash::LegacyHash;
+    use merkle_tree_cairo::attestation::verify_attestation;
 
     #[derive(Copy, Drop, Debug, Hash, starknet::Store, Serde, PartialEq)]
     struct Allocation {
@@ -33,7 +50,17 @@ pub mod Claimer {
 
     #[storage]
     struct Storage {
+        // Account allowed to set the root and the root signer.
+        owner: ContractAddress,
         merkle_root: felt252,
+        // Stark public key whose attestations set_attested_merkle_root accepts.
+        root_signer: felt252,
+        // Campaign the attestations must be signed for.
+        campaign_id: felt252,
+        // Last wave accepted by set_attested_merkle_root; earlier waves are rejected.
+        last_wave: u64,
+        // Whether set_attested_merkle_root accepted a wave yet, so wave 0 can come first.
+        has_attested_wave: bool,
         // Mapping from .
         allocations_claimed: LegacyMap<
             Allocation, bool
@@ -45,6 +72,7 @@ pub mod Claimer {
     #[derive(Drop, starknet::Event)]
     pub enum Event {
         Claimed: Claimed,
+        RootAttested: RootAttested,
     }
 
     #[derive(Drop, starknet::Event)]
@@ -54,6 +82,20 @@ pub mod Claimer {
         pub timestamp: u128
     }
 
+    #[derive(Drop, starknet::Event)]
+    pub struct RootAttested {
+        pub root: felt252,
+        pub campaign_id: felt252,
+        pub wave: u64,
+        pub input_digest: felt252
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, owner: ContractAddress, campaign_id: felt252) {
+        self.owner.write(owner);
+        self.campaign_id.write(campaign_id);
+    }
+
     // Externals
     #[abi(embed_v0)]
     impl ClaimerImpl of super::IClaimer<ContractState> {
@@ -110,11 +152,53 @@ pub mod Claimer {
         }
 
         fn set_merkle_root(ref self: ContractState, root: felt252) {
+            assert(get_caller_address() == self.owner.read(), 'Caller is not owner');
             self.merkle_root.write(root);
         }
 
         fn get_merkle_root(ref self: ContractState) -> felt252 {
             self.merkle_root.read()
         }
+
+        fn set_root_signer(ref self: ContractState, signer: felt252) {
+            assert(get_caller_address() == self.owner.read(), 'Caller is not owner');
+            self.root_signer.write(signer);
+        }
+
+        fn get_root_signer(ref self: ContractState) -> felt252 {
+            self.root_signer.read()
+        }
+
+        fn get_last_wave(ref self: ContractState) -> u64 {
+            self.last_wave.read()
+        }
+
+        fn set_attested_merkle_root(
+            ref self: ContractState,
+            root: felt252,
+            campaign_id: felt252,
+            wave: u64,
+            input_digest: felt252,
+            signature_r: felt252,
+            signature_s: felt252
+        ) {
+            // [Verify the attestation]
+            let signer = self.root_signer.read();
+            assert(signer != 0, 'Root signer not set');
+            let valid = verify_attestation(
+                signer, campaign_id, wave, root, input_digest, signature_r, signature_s
+            );
+            assert(valid, 'Invalid attestation');
+
+            // [Reject other campaigns, replays and rollbacks]
+            assert(campaign_id == self.campaign_id.read(), 'Unknown campaign');
+            let fresh = !self.has_attested_wave.read() || wave > self.last_wave.read();
+            assert(fresh, 'Stale wave');
+
+            self.has_attested_wave.write(true);
+            self.last_wave.write(wave);
+            self.merkle_root.write(root);
+            self.emit(RootAttested { root, campaign_id, wave, input_digest });
+        }
     }
 }
@@ -1 +1,6 @@
+pub mod attestation;
 pub mod claimer;
+pub mod mmr;
+pub mod sum_tree;
+pub mod vesting;
+pub mod vesting_claimer;
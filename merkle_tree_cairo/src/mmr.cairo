@@ -0,0 +1,120 @@
+use core::hash::LegacyHash;
+use core::poseidon::hades_permutation;
+
+/// Hash function of the nodes and peak bagging, as `MmrHasher` in Rust.
+#[derive(Copy, Drop, Debug, Serde, PartialEq)]
+pub enum MmrHasher {
+    Pedersen,
+    Poseidon,
+}
+
+pub fn hash(hasher: MmrHasher, a: felt252, b: felt252) -> felt252 {
+    match hasher {
+        MmrHasher::Pedersen => LegacyHash::hash(a, b),
+        MmrHasher::Poseidon => {
+            let (hash, _, _) = hades_permutation(a, b, 2);
+            hash
+        },
+    }
+}
+
+/// Hashes two nodes in sorted order, like the `Claimer`.
+pub fn hash_pair(hasher: MmrHasher, a: felt252, b: felt252) -> felt252 {
+    let a_u256: u256 = a.into();
+    let b_u256: u256 = b.into();
+    if a_u256 < b_u256 {
+        hash(hasher, a, b)
+    } else {
+        hash(hasher, b, a)
+    }
+}
+
+/// Bags peaks from right to left, then commits to the leaf count.
+pub fn bag_peaks(hasher: MmrHasher, leaf_count: u64, mut peaks: Span<felt252>) -> felt252 {
+    let mut bagged = match peaks.pop_back() {
+        Option::Some(peak) => *peak,
+        Option::None => 0,
+    };
+    loop {
+        match peaks.pop_back() {
+            Option::Some(peak) => { bagged = hash(hasher, *peak, bagged); },
+            Option::None => { break; },
+        };
+    };
+    hash(hasher, leaf_count.into(), bagged)
+}
+
+/// Checks a leaf against the bagged root of a range of `leaf_count` leaves.
+/// `siblings` go from the leaf up to the peak of its mountain, `peaks` list every mountain
+/// from the highest one.
+pub fn verify_mmr_proof(
+    hasher: MmrHasher,
+    root: felt252,
+    leaf: felt252,
+    leaf_index: u64,
+    leaf_count: u64,
+    mut siblings: Span<felt252>,
+    peaks: Span<felt252>
+) -> bool {
+    if leaf_index >= leaf_count || peaks.len() != mountain_count(leaf_count) {
+        return false;
+    }
+
+    // Mountains are the powers of two of the leaf count, highest first
+    let mut remaining = leaf_count;
+    let mut start = 0;
+    let mut mountain = 0;
+    let mut height = 0;
+    loop {
+        let (size, mountain_height) = highest_power_of_two(remaining);
+        if leaf_index < start + size {
+            height = mountain_height;
+            break;
+        }
+        start += size;
+        remaining -= size;
+        mountain += 1;
+    };
+    if siblings.len() != height {
+        return false;
+    }
+
+    let mut peak = leaf;
+    loop {
+        match siblings.pop_front() {
+            Option::Some(sibling) => { peak = hash_pair(hasher, peak, *sibling); },
+            Option::None => { break; },
+        };
+    };
+    *peaks.at(mountain) == peak && bag_peaks(hasher, leaf_count, peaks) == root
+}
+
+/// Number of mountains, one per bit set in the leaf count.
+fn mountain_count(leaf_count: u64) -> u32 {
+    let mut remaining = leaf_count;
+    let mut count = 0;
+    loop {
+        if remaining == 0 {
+            break;
+        }
+        if remaining % 2 == 1 {
+            count += 1;
+        }
+        remaining /= 2;
+    };
+    count
+}
+
+/// Largest power of two not above `value`, with its exponent.
+fn highest_power_of_two(value: u64) -> (u64, u32) {
+    let mut size = 1;
+    let mut height = 0;
+    loop {
+        if size > value / 2 {
+            break;
+        }
+        size *= 2;
+        height += 1;
+    };
+    (size, height)
+}
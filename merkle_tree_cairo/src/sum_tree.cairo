@@ -0,0 +1,47 @@
+use core::hash::LegacyHash;
+
+/// A node committing to a hash and to the total amount of the leaves below it.
+#[derive(Copy, Drop, Debug, Serde, PartialEq)]
+pub struct SumNode {
+    pub hash: felt252,
+    pub sum: u128,
+}
+
+/// Builds the leaf of an allocation: the `Claimer` leaf hash and the allocated amount.
+pub fn leaf_node(claimee: felt252, amount: u128, timestamp: u128, id: u128) -> SumNode {
+    let intermediate_hash = LegacyHash::hash(claimee, amount.into());
+    let intermediate_hash = LegacyHash::hash(intermediate_hash, timestamp.into());
+    let hash = LegacyHash::hash(intermediate_hash, id.into());
+    SumNode { hash, sum: amount }
+}
+
+/// Combines two nodes: the children are hashed in sorted order, then with their subtotal.
+/// Overflowing subtotals panic, so a proof cannot wrap the total around.
+pub fn hash_sum_pair(a: SumNode, b: SumNode) -> SumNode {
+    let a_hash: u256 = a.hash.into();
+    let b_hash: u256 = b.hash.into();
+    let pair = if a_hash < b_hash {
+        LegacyHash::hash(a.hash, b.hash)
+    } else {
+        LegacyHash::hash(b.hash, a.hash)
+    };
+    let sum = a.sum + b.sum;
+    SumNode { hash: LegacyHash::hash(pair, sum.into()), sum }
+}
+
+/// Folds a proof into the root it commits to, adding up subtotals along the path.
+pub fn compute_sum_root(leaf: SumNode, mut proof: Span<SumNode>) -> SumNode {
+    let mut current = leaf;
+    loop {
+        match proof.pop_front() {
+            Option::Some(sibling) => { current = hash_sum_pair(current, *sibling); },
+            Option::None => { break; },
+        };
+    };
+    current
+}
+
+/// Checks that a leaf and its proof resolve to the expected root hash and total.
+pub fn verify_sum_proof(root: SumNode, leaf: SumNode, proof: Span<SumNode>) -> bool {
+    compute_sum_root(leaf, proof) == root
+}
@@ -0,0 +1,33 @@
+use core::hash::LegacyHash;
+
+/// Leaf of a vesting schedule, chained with Pedersen like an allocation leaf:
+/// `VestingSchedule::leaf_hash` in Rust.
+pub fn vesting_leaf(
+    beneficiary: felt252, total: u128, start: u64, cliff: u64, duration: u64, id: u128
+) -> felt252 {
+    let hash = LegacyHash::hash(beneficiary, total.into());
+    let hash = LegacyHash::hash(hash, start.into());
+    let hash = LegacyHash::hash(hash, cliff.into());
+    let hash = LegacyHash::hash(hash, duration.into());
+    LegacyHash::hash(hash, id.into())
+}
+
+/// Amount vested at `timestamp`: nothing before `start + cliff`, then linear until
+/// `start + duration`, rounded down.
+pub fn vested_amount(total: u128, start: u64, cliff: u64, duration: u64, timestamp: u64) -> u128 {
+    if timestamp < start {
+        return 0;
+    }
+    let elapsed = timestamp - start;
+    if elapsed < cliff {
+        return 0;
+    }
+    if elapsed >= duration {
+        return total;
+    }
+
+    let total: u256 = total.into();
+    let elapsed: u256 = elapsed.into();
+    let duration: u256 = duration.into();
+    (total * elapsed / duration).try_into().unwrap()
+}
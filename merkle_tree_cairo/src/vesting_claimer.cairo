@@ -0,0 +1,149 @@
+use starknet::ContractAddress;
+
+#[starknet::interface]
+pub trait IVestingClaimer<TContractState> {
+    fn release(
+        ref self: TContractState,
+        total: u128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        id: u128,
+        proof: Array::<felt252>
+    );
+
+    fn get_released(
+        ref self: TContractState,
+        beneficiary: ContractAddress,
+        total: u128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        id: u128
+    ) -> u128;
+
+    fn get_releasable(
+        ref self: TContractState,
+        beneficiary: ContractAddress,
+        total: u128,
+        start: u64,
+        cliff: u64,
+        duration: u64,
+        id: u128
+    ) -> u128;
+
+    fn set_merkle_root(ref self: TContractState, root: felt252);
+
+    fn get_merkle_root(ref self: TContractState) -> felt252;
+}
+
+#[starknet::contract]
+pub mod VestingClaimer {
+    use starknet::{ContractAddress, get_caller_address, get_block_timestamp};
+    use alexandria_merkle_tree::merkle_tree::{
+        Hasher, MerkleTree, MerkleTreeImpl, pedersen::PedersenHasherImpl, MerkleTreeTrait,
+    };
+    use merkle_tree_cairo::vesting::{vesting_leaf, vested_amount};
+
+    #[storage]
+    struct Storage {
+        // Account allowed to set the root.
+        owner: ContractAddress,
+        merkle_root: felt252,
+        // Root each leaf's proof was last checked against; a new root needs a new proof.
+        schedules_verified_root: LegacyMap<felt252, felt252>,
+        // Amount released so far for each leaf.
+        schedules_released: LegacyMap<felt252, u128>,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    pub enum Event {
+        Released: Released,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    pub struct Released {
+        pub beneficiary: ContractAddress,
+        pub id: u128,
+        pub amount: u128,
+        pub total_released: u128
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, owner: ContractAddress) {
+        self.owner.write(owner);
+    }
+
+    // Externals
+    #[abi(embed_v0)]
+    impl VestingClaimerImpl of super::IVestingClaimer<ContractState> {
+        fn release(
+            ref self: ContractState,
+            total: u128,
+            start: u64,
+            cliff: u64,
+            duration: u64,
+            id: u128,
+            proof: Array::<felt252>
+        ) {
+            let beneficiary = get_caller_address();
+            let leaf = vesting_leaf(beneficiary.into(), total, start, cliff, duration, id);
+
+            // [Verify the proof unless it was already checked against the current root]
+            let root = self.merkle_root.read();
+            if root == 0 || self.schedules_verified_root.read(leaf) != root {
+                let mut merkle_tree: MerkleTree<Hasher> = MerkleTreeImpl::new();
+                let root_computed = merkle_tree.compute_root(leaf, proof.span());
+                assert(root_computed == root, 'Invalid proof');
+                self.schedules_verified_root.write(leaf, root);
+            }
+
+            // [Release what vested since the last release]
+            let vested = vested_amount(total, start, cliff, duration, get_block_timestamp());
+            let released = self.schedules_released.read(leaf);
+            assert(vested > released, 'Nothing to release');
+            self.schedules_released.write(leaf, vested);
+
+            // [Emit event]
+            let amount = vested - released;
+            self.emit(Released { beneficiary, id, amount, total_released: vested });
+        }
+
+        fn get_released(
+            ref self: ContractState,
+            beneficiary: ContractAddress,
+            total: u128,
+            start: u64,
+            cliff: u64,
+            duration: u64,
+            id: u128
+        ) -> u128 {
+            let leaf = vesting_leaf(beneficiary.into(), total, start, cliff, duration, id);
+            self.schedules_released.read(leaf)
+        }
+
+        fn get_releasable(
+            ref self: ContractState,
+            beneficiary: ContractAddress,
+            total: u128,
+            start: u64,
+            cliff: u64,
+            duration: u64,
+            id: u128
+        ) -> u128 {
+            // does not check the schedule against the root, release does
+            let released = self.get_released(beneficiary, total, start, cliff, duration, id);
+            vested_amount(total, start, cliff, duration, get_block_timestamp()) - released
+        }
+
+        fn set_merkle_root(ref self: ContractState, root: felt252) {
+            assert(get_caller_address() == self.owner.read(), 'Caller is not the owner');
+            self.merkle_root.write(root);
+        }
+
+        fn get_merkle_root(ref self: ContractState) -> felt252 {
+            self.merkle_root.read()
+        }
+    }
+}
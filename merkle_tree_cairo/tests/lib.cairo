@@ -1,2 +1,8 @@
+mod tests_attestation;
 mod tests_merkle_tree;
+mod tests_mmr;
+mod tests_sum_tree;
+mod tests_test_vectors;
+mod tests_vesting;
 pub mod constants;
+pub mod test_vectors;
@@ -0,0 +1,410 @@
+// Generated by test_vectors/generate_cairo.py from merkle_tree_v1.json, do not edit.
+
+pub const TEST_VECTORS_VERSION: u32 = 1;
+
+#[derive(Drop)]
+pub struct VectorLeaf {
+    pub claimee: felt252,
+    pub amount: u128,
+    pub timestamp: u128,
+    pub id: u128,
+    pub leaf: felt252,
+    pub proof: Array<felt252>
+}
+
+#[derive(Drop)]
+pub struct TestVector {
+    pub name: felt252,
+    pub root: felt252,
+    pub leaves: Array<VectorLeaf>
+}
+
+pub fn test_vectors() -> Array<TestVector> {
+    array![
+        TestVector {
+            name: 'single_leaf',
+            root: 0x457477415ee47473da0e75b510544259590bac303892b92cb26b01e311a3480,
+            leaves: array![
+                VectorLeaf {
+                    claimee: 0x1234567890abcdef1234567890abcdef12345678,
+                    amount: 150,
+                    timestamp: 2,
+                    id: 1,
+                    leaf: 0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58,
+                    proof: array![
+                        0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58
+                    ]
+                }
+            ]
+        },
+        TestVector {
+            name: 'two_leaves',
+            root: 0xe9fa78e0a7ef428a5f2eff184a26cfc24ff838c41dd092889844ca8fb49a57,
+            leaves: array![
+                VectorLeaf {
+                    claimee: 0x1234567890abcdef1234567890abcdef12345678,
+                    amount: 150,
+                    timestamp: 2,
+                    id: 1,
+                    leaf: 0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58,
+                    proof: array![
+                        0x46f948acb5b407293e7118b3158c62228e6d94c5a563d2522bef8c589d38363
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0xabcdefabcdefabcdefabcdefabcdefabcdef1234,
+                    amount: 200,
+                    timestamp: 3,
+                    id: 2,
+                    leaf: 0x46f948acb5b407293e7118b3158c62228e6d94c5a563d2522bef8c589d38363,
+                    proof: array![
+                        0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58
+                    ]
+                }
+            ]
+        },
+        TestVector {
+            name: 'three_leaves',
+            root: 0x77f216ccd342ff93d79c32aae18d49fbdb05a1f4ced3a6bfe1ebb4fb3e4a2d6,
+            leaves: array![
+                VectorLeaf {
+                    claimee: 0x1234567890abcdef1234567890abcdef12345678,
+                    amount: 150,
+                    timestamp: 2,
+                    id: 1,
+                    leaf: 0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58,
+                    proof: array![
+                        0x46f948acb5b407293e7118b3158c62228e6d94c5a563d2522bef8c589d38363,
+                        0x76d8f3e188e5afa7e300d959d7b1e3b905530583a961224f617bfba0bab714f
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0xabcdefabcdefabcdefabcdefabcdefabcdef1234,
+                    amount: 200,
+                    timestamp: 3,
+                    id: 2,
+                    leaf: 0x46f948acb5b407293e7118b3158c62228e6d94c5a563d2522bef8c589d38363,
+                    proof: array![
+                        0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58,
+                        0x76d8f3e188e5afa7e300d959d7b1e3b905530583a961224f617bfba0bab714f
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x3f5a1e9dab72f1a8c12d4d9b3a58a7b4425e7b4c,
+                    amount: 250,
+                    timestamp: 4,
+                    id: 5,
+                    leaf: 0x57d0a984fe6da387abe199923d7ab26791ac965b223a424f036ee3c901a58cb,
+                    proof: array![
+                        0x57d0a984fe6da387abe199923d7ab26791ac965b223a424f036ee3c901a58cb,
+                        0xe9fa78e0a7ef428a5f2eff184a26cfc24ff838c41dd092889844ca8fb49a57
+                    ]
+                }
+            ]
+        },
+        TestVector {
+            name: 'five_leaves',
+            root: 0x149ac48d8b604122fb9e783dea13aea22ddb99669d88f4674f828384e309846,
+            leaves: array![
+                VectorLeaf {
+                    claimee: 0x1000,
+                    amount: 100,
+                    timestamp: 1716518912,
+                    id: 1,
+                    leaf: 0x34a98a8675a748c185a1a7641a6b20032b0387b86a81d69a6fe4b521ac1e675,
+                    proof: array![
+                        0x1005e097f801626e7b06cd17965b111fefbd5a96582e4aa8af4bdb30049e85,
+                        0x5d00a24683108c5fc8c0e6dd776d703d937f9c3f1ccf1cfe105fb12f5a6885d,
+                        0x3156cac2087b912a694bff4aed9c22e3acfa79a811c045e447bd0e7f868d449
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x1001,
+                    amount: 200,
+                    timestamp: 1716518913,
+                    id: 2,
+                    leaf: 0x1005e097f801626e7b06cd17965b111fefbd5a96582e4aa8af4bdb30049e85,
+                    proof: array![
+                        0x34a98a8675a748c185a1a7641a6b20032b0387b86a81d69a6fe4b521ac1e675,
+                        0x5d00a24683108c5fc8c0e6dd776d703d937f9c3f1ccf1cfe105fb12f5a6885d,
+                        0x3156cac2087b912a694bff4aed9c22e3acfa79a811c045e447bd0e7f868d449
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x1002,
+                    amount: 300,
+                    timestamp: 1716518914,
+                    id: 3,
+                    leaf: 0x31574bd0f914306b1aac4e7e5f496e52f232ca96958929e7aa35dca728ec007,
+                    proof: array![
+                        0x50c745a3d657c315622bd663d5960e65b5a03c07dec2d8fe5b8da0f9bbff71,
+                        0x5dce5e9e43d9517f36c2538ab899d86d71146403352475a2a2c5ded57e6d85a,
+                        0x3156cac2087b912a694bff4aed9c22e3acfa79a811c045e447bd0e7f868d449
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x1003,
+                    amount: 400,
+                    timestamp: 1716518915,
+                    id: 4,
+                    leaf: 0x50c745a3d657c315622bd663d5960e65b5a03c07dec2d8fe5b8da0f9bbff71,
+                    proof: array![
+                        0x31574bd0f914306b1aac4e7e5f496e52f232ca96958929e7aa35dca728ec007,
+                        0x5dce5e9e43d9517f36c2538ab899d86d71146403352475a2a2c5ded57e6d85a,
+                        0x3156cac2087b912a694bff4aed9c22e3acfa79a811c045e447bd0e7f868d449
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x1004,
+                    amount: 500,
+                    timestamp: 1716518916,
+                    id: 5,
+                    leaf: 0x625ee6a2430140c4ecb1fd07c7f3dc79c8b8fba2ca1415e0e09460a501f7c35,
+                    proof: array![
+                        0x625ee6a2430140c4ecb1fd07c7f3dc79c8b8fba2ca1415e0e09460a501f7c35,
+                        0x4c81b0a15d9a7f078971b45ebd9147f1204e7f42a8fc5c63f5e6ac33c678c0,
+                        0xd5212415ecde2458372befee1a276b9835e09e176e97c8a80b339b80e55ea8
+                    ]
+                }
+            ]
+        },
+        TestVector {
+            name: 'eleven_leaves',
+            root: 0x3bd8640a37de2b9fede155797adf55c711d6fbbf0d23617b9bd421c92513548,
+            leaves: array![
+                VectorLeaf {
+                    claimee: 0x2000,
+                    amount: 100,
+                    timestamp: 1716518912,
+                    id: 1,
+                    leaf: 0x73870f2fbf16a7be15a7ab2b20c56f12d5f0fa885fb2e306e5f6fa65772237b,
+                    proof: array![
+                        0x3dbd337ae6d3e037a3b09a8d7a7356e95323ab5fde8fc955ecce417624c9d3d,
+                        0x1b162d561d0a4ce37e1ca86223b2c360b522669958eae82a1a7b2d9142f4877,
+                        0x156731b6566869fcebce6e60280e76969ab1f5253e342348420b410b99fe55c,
+                        0x4f928f22095e6b60a252492ab330261212b0e547dfdda81dccb9c43856afecd
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2001,
+                    amount: 200,
+                    timestamp: 1716518913,
+                    id: 2,
+                    leaf: 0x3dbd337ae6d3e037a3b09a8d7a7356e95323ab5fde8fc955ecce417624c9d3d,
+                    proof: array![
+                        0x73870f2fbf16a7be15a7ab2b20c56f12d5f0fa885fb2e306e5f6fa65772237b,
+                        0x1b162d561d0a4ce37e1ca86223b2c360b522669958eae82a1a7b2d9142f4877,
+                        0x156731b6566869fcebce6e60280e76969ab1f5253e342348420b410b99fe55c,
+                        0x4f928f22095e6b60a252492ab330261212b0e547dfdda81dccb9c43856afecd
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2002,
+                    amount: 300,
+                    timestamp: 1716518914,
+                    id: 3,
+                    leaf: 0x3f66659aa6a3050454ddb145baba0f51c12022d47b2613e15b094f9ff993928,
+                    proof: array![
+                        0x35206575a60e773e8919afa8ae633bb9dd9d22c7c515d59780d2e8de70d58cf,
+                        0x34889f8b2efff402b9d0a09ecb7dd417a9cb1829ac0a3f61da9652e14a5ab40,
+                        0x156731b6566869fcebce6e60280e76969ab1f5253e342348420b410b99fe55c,
+                        0x4f928f22095e6b60a252492ab330261212b0e547dfdda81dccb9c43856afecd
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2003,
+                    amount: 400,
+                    timestamp: 1716518915,
+                    id: 4,
+                    leaf: 0x35206575a60e773e8919afa8ae633bb9dd9d22c7c515d59780d2e8de70d58cf,
+                    proof: array![
+                        0x3f66659aa6a3050454ddb145baba0f51c12022d47b2613e15b094f9ff993928,
+                        0x34889f8b2efff402b9d0a09ecb7dd417a9cb1829ac0a3f61da9652e14a5ab40,
+                        0x156731b6566869fcebce6e60280e76969ab1f5253e342348420b410b99fe55c,
+                        0x4f928f22095e6b60a252492ab330261212b0e547dfdda81dccb9c43856afecd
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2004,
+                    amount: 500,
+                    timestamp: 1716518916,
+                    id: 5,
+                    leaf: 0x8d4d5494a0c15d87a8b4fca2df4b578c44375ad3239092f4766a2e2d1bded9,
+                    proof: array![
+                        0xf507e8a5326d88b881b82150bbcc1cfc02095b7a998b9a37220fc0827c2a47,
+                        0x7d5bfe24273be8fe0f4eccfb17d2962d5f4da3f2d760f1f3b848bfed3e0bef3,
+                        0xfc7e712992cd076392e73efc05240709053bf2889cda6608bf1a3d6c1033dd,
+                        0x4f928f22095e6b60a252492ab330261212b0e547dfdda81dccb9c43856afecd
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2005,
+                    amount: 600,
+                    timestamp: 1716518917,
+                    id: 6,
+                    leaf: 0xf507e8a5326d88b881b82150bbcc1cfc02095b7a998b9a37220fc0827c2a47,
+                    proof: array![
+                        0x8d4d5494a0c15d87a8b4fca2df4b578c44375ad3239092f4766a2e2d1bded9,
+                        0x7d5bfe24273be8fe0f4eccfb17d2962d5f4da3f2d760f1f3b848bfed3e0bef3,
+                        0xfc7e712992cd076392e73efc05240709053bf2889cda6608bf1a3d6c1033dd,
+                        0x4f928f22095e6b60a252492ab330261212b0e547dfdda81dccb9c43856afecd
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2006,
+                    amount: 700,
+                    timestamp: 1716518918,
+                    id: 7,
+                    leaf: 0x730daf9fbfcc17632ff1a4685b488f74dd92c394fd376a3ec2b49f1af2dfb10,
+                    proof: array![
+                        0x57c3a667a30c32f0634146487351844f604d8bd6d55a6051410f3518d01259d,
+                        0x13e4066e7c42ff4da684a9062a5191ef5547168a87c9e003423b2ff2c57cf32,
+                        0xfc7e712992cd076392e73efc05240709053bf2889cda6608bf1a3d6c1033dd,
+                        0x4f928f22095e6b60a252492ab330261212b0e547dfdda81dccb9c43856afecd
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2007,
+                    amount: 800,
+                    timestamp: 1716518919,
+                    id: 8,
+                    leaf: 0x57c3a667a30c32f0634146487351844f604d8bd6d55a6051410f3518d01259d,
+                    proof: array![
+                        0x730daf9fbfcc17632ff1a4685b488f74dd92c394fd376a3ec2b49f1af2dfb10,
+                        0x13e4066e7c42ff4da684a9062a5191ef5547168a87c9e003423b2ff2c57cf32,
+                        0xfc7e712992cd076392e73efc05240709053bf2889cda6608bf1a3d6c1033dd,
+                        0x4f928f22095e6b60a252492ab330261212b0e547dfdda81dccb9c43856afecd
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2008,
+                    amount: 900,
+                    timestamp: 1716518920,
+                    id: 9,
+                    leaf: 0x7c06558a9dbb9402f3ffd44fecd6ea3c4ff531eed288e9dc2b8fe84654027c2,
+                    proof: array![
+                        0x72b6afbe13575ef8959fd4395ff0ff0f00a06c5d294a54f89f9d51b45db693f,
+                        0x2b592bfa087398c680cc2d9286ad801e796f89ed88d197bbf5056af72ab4d36,
+                        0x5348db8dbf3cfaeffda10eddcf402e54b58a9d5ac09e2ad83652ea5dd261267,
+                        0x180aa888f9c3436202486c073d3da5fb3bea5b4ec3356c0c9999016ed72bea1
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2009,
+                    amount: 1000,
+                    timestamp: 1716518921,
+                    id: 10,
+                    leaf: 0x72b6afbe13575ef8959fd4395ff0ff0f00a06c5d294a54f89f9d51b45db693f,
+                    proof: array![
+                        0x7c06558a9dbb9402f3ffd44fecd6ea3c4ff531eed288e9dc2b8fe84654027c2,
+                        0x2b592bfa087398c680cc2d9286ad801e796f89ed88d197bbf5056af72ab4d36,
+                        0x5348db8dbf3cfaeffda10eddcf402e54b58a9d5ac09e2ad83652ea5dd261267,
+                        0x180aa888f9c3436202486c073d3da5fb3bea5b4ec3356c0c9999016ed72bea1
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x200a,
+                    amount: 1100,
+                    timestamp: 1716518922,
+                    id: 11,
+                    leaf: 0x4f8b989b9b4cd34584602cbbcbc82868f96fd47687c7b224ff4243c30d859fb,
+                    proof: array![
+                        0x4f8b989b9b4cd34584602cbbcbc82868f96fd47687c7b224ff4243c30d859fb,
+                        0x62dc27303a29e0ce9d828f6e9478ed0c5ce2c3e57cedd798ee39ac62586a2bd,
+                        0x5348db8dbf3cfaeffda10eddcf402e54b58a9d5ac09e2ad83652ea5dd261267,
+                        0x180aa888f9c3436202486c073d3da5fb3bea5b4ec3356c0c9999016ed72bea1
+                    ]
+                }
+            ]
+        },
+        TestVector {
+            name: 'duplicate_amounts',
+            root: 0x7755053944f7eab178e3ceb9ec156e73c4eea2f2b63029f3654e96db503bdf4,
+            leaves: array![
+                VectorLeaf {
+                    claimee: 0x1111,
+                    amount: 500,
+                    timestamp: 1694498816,
+                    id: 1,
+                    leaf: 0x60e5f68d692cbf50575a83c1ec9984532352a8f6f643c9eb7c6651d41d67f8d,
+                    proof: array![
+                        0x46ac955fe8b38c27a0f3aaffa9e1f06a10a3346b672ab4720f10cb3b04a5a96,
+                        0x6c6369fab3d7b6bc67e32ec8daed9173bcef13024ba1e05736d85d9fbf4872e
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x2222,
+                    amount: 500,
+                    timestamp: 1694498816,
+                    id: 1,
+                    leaf: 0x46ac955fe8b38c27a0f3aaffa9e1f06a10a3346b672ab4720f10cb3b04a5a96,
+                    proof: array![
+                        0x60e5f68d692cbf50575a83c1ec9984532352a8f6f643c9eb7c6651d41d67f8d,
+                        0x6c6369fab3d7b6bc67e32ec8daed9173bcef13024ba1e05736d85d9fbf4872e
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x3333,
+                    amount: 500,
+                    timestamp: 1694498816,
+                    id: 1,
+                    leaf: 0x592e87f50f848ac5f678a7978beb5fc5a2dc5faa3d6c908619a088e1de06a23,
+                    proof: array![
+                        0xc9f3a789826b187a6b1606dd8fb4b13addf76af5d0ae0b9ab36a46fb69216a,
+                        0x36d35951dc91bf95430e6985e7db0f57e5c0572a284a312f910b1b6264d566
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x1111,
+                    amount: 500,
+                    timestamp: 1694498816,
+                    id: 2,
+                    leaf: 0xc9f3a789826b187a6b1606dd8fb4b13addf76af5d0ae0b9ab36a46fb69216a,
+                    proof: array![
+                        0x592e87f50f848ac5f678a7978beb5fc5a2dc5faa3d6c908619a088e1de06a23,
+                        0x36d35951dc91bf95430e6985e7db0f57e5c0572a284a312f910b1b6264d566
+                    ]
+                }
+            ]
+        },
+        TestVector {
+            name: 'large_felts',
+            root: 0x56af0a827a59ea78b625d13879118dbe3662a9f97e253286cac1846ecb5993a,
+            leaves: array![
+                VectorLeaf {
+                    claimee: 0x800000000000011000000000000000000000000000000000000000000000000,
+                    amount: 18446744073709551615,
+                    timestamp: 340282366920938463463374607431768211455,
+                    id: 18446744073709551615,
+                    leaf: 0x571852bcf42fdda37b4d77376898f063aa6891dacc7712706b832eea263e0ea,
+                    proof: array![
+                        0x408360821ba7efdf6524e7668d4abde292048ecf6f7c4e20aaa4bee59a82262,
+                        0x34518e6635da46eabb4096c28a37f629c317270c794427c02b66f0cb367243
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x7ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00,
+                    amount: 18446744073709551615,
+                    timestamp: 340282366920938463463374607431768211455,
+                    id: 0,
+                    leaf: 0x408360821ba7efdf6524e7668d4abde292048ecf6f7c4e20aaa4bee59a82262,
+                    proof: array![
+                        0x571852bcf42fdda37b4d77376898f063aa6891dacc7712706b832eea263e0ea,
+                        0x34518e6635da46eabb4096c28a37f629c317270c794427c02b66f0cb367243
+                    ]
+                },
+                VectorLeaf {
+                    claimee: 0x1,
+                    amount: 0,
+                    timestamp: 0,
+                    id: 0,
+                    leaf: 0x5b2721173930114b479cbeda6de55737a4389dc41e49ec310df094e88d016c0,
+                    proof: array![
+                        0x5b2721173930114b479cbeda6de55737a4389dc41e49ec310df094e88d016c0,
+                        0x1dd983efb2ec5656ef3de5191cb18027a74d88141864cdecc6c3bf2c5bb7d25
+                    ]
+                }
+            ]
+        }
+    ]
+}
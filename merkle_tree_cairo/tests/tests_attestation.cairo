@@ -0,0 +1,209 @@
+use starknet::ContractAddress;
+use snforge_std as snf;
+use snforge_std::{ContractClassTrait, test_address, spy_events, EventSpyAssertionsTrait};
+
+use merkle_tree_cairo::attestation::{attestation_hash, verify_attestation};
+use merkle_tree_cairo::claimer::{Claimer, IClaimerDispatcher, IClaimerDispatcherTrait};
+
+use super::constants::{MERKLE_ROOT_FIRST_WAVE, MERKLE_ROOT_SECOND_WAVE};
+
+// Same vector as test_signature_matches_cairo in merkle_tree_rust/tests/attestation_tests.rs,
+// signed with the private key 0x1234567890987654321.
+const SIGNER: felt252 = 0x20c29f1c98f3320d56f01c13372c923123c35828bce54f2153aa1cfe61c44f2;
+const CAMPAIGN_ID: felt252 = 'carbonable';
+const WAVE: u64 = 1;
+const INPUT_DIGEST: felt252 = 0xabc;
+const MESSAGE_HASH: felt252 = 0x3bf12be181772522b3d642c4ed6aaa3d6272837ee8c585a1a295f84841d3639;
+const SIGNATURE_R: felt252 = 0x267f8951337353faf404189d20411e2c49ca6cdd4655640c5198d63a461f03c;
+const SIGNATURE_S: felt252 = 0x5e483b241e708d71f005cd75de891167dccba0844a075e204b9559bda854fef;
+// Second wave, same vector as test_second_wave_signature_matches_cairo.
+const SECOND_SIGNATURE_R: felt252 =
+    0x4d5a8564df35a56c970fc21bf3aabe3b38fd2e7bb524bad5828889cd09b0ece;
+const SECOND_SIGNATURE_S: felt252 =
+    0x30b51c075f8d47298ef9661b6539100aa89718fbd82d9489c8875ae7ae22cd4;
+// Wave 0 of the first wave root, same vector as test_wave_zero_signature_matches_cairo.
+const WAVE_ZERO_SIGNATURE_R: felt252 =
+    0x37cba89bc68de4a12342f1537349772f1934d8d8e514e7a5901d621b68b3d0a;
+const WAVE_ZERO_SIGNATURE_S: felt252 =
+    0xe50b47bb308d30df3a71a4efc52444a963208b2b706da69fdfef73f44ec400;
+
+fn deploy_contract(campaign_id: felt252) -> ContractAddress {
+    let contract = snf::declare("Claimer").expect('Contract declaration failed');
+
+    let calldata: Array<felt252> = array![test_address().into(), campaign_id];
+    let (contract_address, _) = contract.deploy(@calldata).expect('Contract deployment failed');
+
+    contract_address
+}
+
+#[test]
+fn test_attestation_hash_matches_rust() {
+    let hash = attestation_hash(CAMPAIGN_ID, WAVE, MERKLE_ROOT_FIRST_WAVE, INPUT_DIGEST);
+    assert_eq!(hash, MESSAGE_HASH);
+}
+
+#[test]
+fn test_verify_attestation() {
+    let valid = verify_attestation(
+        SIGNER, CAMPAIGN_ID, WAVE, MERKLE_ROOT_FIRST_WAVE, INPUT_DIGEST, SIGNATURE_R, SIGNATURE_S
+    );
+    assert!(valid);
+}
+
+#[test]
+fn test_verify_attestation_of_another_wave_fails() {
+    /// The signature covers the wave, so it does not verify for another one. Replaying it for
+    /// its own wave is rejected by the Claimer, see test_replay_attested_merkle_root.
+    let valid = verify_attestation(
+        SIGNER, CAMPAIGN_ID, 2, MERKLE_ROOT_FIRST_WAVE, INPUT_DIGEST, SIGNATURE_R, SIGNATURE_S
+    );
+    assert!(!valid);
+}
+
+fn deploy_with_signer(campaign_id: felt252, signer: felt252) -> IClaimerDispatcher {
+    let contract_address = deploy_contract(campaign_id);
+    let contract = IClaimerDispatcher { contract_address };
+    contract.set_root_signer(signer);
+    contract
+}
+
+fn attest_first_wave(contract: IClaimerDispatcher) {
+    contract
+        .set_attested_merkle_root(
+            MERKLE_ROOT_FIRST_WAVE, CAMPAIGN_ID, WAVE, INPUT_DIGEST, SIGNATURE_R, SIGNATURE_S
+        );
+}
+
+fn attest_wave_zero(contract: IClaimerDispatcher) {
+    contract
+        .set_attested_merkle_root(
+            MERKLE_ROOT_FIRST_WAVE,
+            CAMPAIGN_ID,
+            0,
+            INPUT_DIGEST,
+            WAVE_ZERO_SIGNATURE_R,
+            WAVE_ZERO_SIGNATURE_S
+        );
+}
+
+fn attest_second_wave(contract: IClaimerDispatcher) {
+    contract
+        .set_attested_merkle_root(
+            MERKLE_ROOT_SECOND_WAVE,
+            CAMPAIGN_ID,
+            WAVE + 1,
+            INPUT_DIGEST,
+            SECOND_SIGNATURE_R,
+            SECOND_SIGNATURE_S
+        );
+}
+
+#[test]
+fn test_set_attested_merkle_root() {
+    let contract = deploy_with_signer(CAMPAIGN_ID, SIGNER);
+    assert_eq!(contract.get_root_signer(), SIGNER);
+
+    let mut spy = spy_events();
+    attest_first_wave(contract);
+    assert_eq!(contract.get_merkle_root(), MERKLE_ROOT_FIRST_WAVE);
+    assert_eq!(contract.get_last_wave(), WAVE);
+
+    let expected_event = Claimer::Event::RootAttested(
+        Claimer::RootAttested {
+            root: MERKLE_ROOT_FIRST_WAVE,
+            campaign_id: CAMPAIGN_ID,
+            wave: WAVE,
+            input_digest: INPUT_DIGEST
+        }
+    );
+    spy.assert_emitted(@array![(contract.contract_address, expected_event)]);
+}
+
+#[test]
+fn test_attested_waves_advance() {
+    let contract = deploy_with_signer(CAMPAIGN_ID, SIGNER);
+
+    attest_first_wave(contract);
+    attest_second_wave(contract);
+    assert_eq!(contract.get_merkle_root(), MERKLE_ROOT_SECOND_WAVE);
+    assert_eq!(contract.get_last_wave(), WAVE + 1);
+}
+
+#[test]
+fn test_attest_wave_zero() {
+    /// Campaign waves are numbered from 0, so the first one is accepted before any other.
+    let contract = deploy_with_signer(CAMPAIGN_ID, SIGNER);
+
+    attest_wave_zero(contract);
+    assert_eq!(contract.get_merkle_root(), MERKLE_ROOT_FIRST_WAVE);
+    assert_eq!(contract.get_last_wave(), 0);
+
+    attest_first_wave(contract);
+    assert_eq!(contract.get_last_wave(), WAVE);
+}
+
+#[test]
+#[should_panic(expected: 'Stale wave')]
+fn test_replay_wave_zero() {
+    let contract = deploy_with_signer(CAMPAIGN_ID, SIGNER);
+
+    attest_wave_zero(contract);
+    attest_wave_zero(contract);
+}
+
+#[test]
+#[should_panic(expected: 'Stale wave')]
+fn test_replay_attested_merkle_root() {
+    let contract = deploy_with_signer(CAMPAIGN_ID, SIGNER);
+
+    attest_first_wave(contract);
+    attest_first_wave(contract);
+}
+
+#[test]
+#[should_panic(expected: 'Stale wave')]
+fn test_roll_back_attested_merkle_root() {
+    /// Once the second wave is accepted, the still valid first wave attestation is not.
+    let contract = deploy_with_signer(CAMPAIGN_ID, SIGNER);
+
+    attest_second_wave(contract);
+    attest_first_wave(contract);
+}
+
+#[test]
+#[should_panic(expected: 'Unknown campaign')]
+fn test_set_attested_merkle_root_for_another_campaign() {
+    /// The signer may attest roots of several campaigns; each Claimer only takes its own.
+    let contract = deploy_with_signer('another campaign', SIGNER);
+
+    attest_first_wave(contract);
+}
+
+#[test]
+#[should_panic(expected: 'Invalid attestation')]
+fn test_set_attested_merkle_root_with_another_root() {
+    /// A valid signature does not carry over to a root it was not made for.
+    let contract = deploy_with_signer(CAMPAIGN_ID, SIGNER);
+
+    contract
+        .set_attested_merkle_root(
+            MERKLE_ROOT_FIRST_WAVE + 1, CAMPAIGN_ID, WAVE, INPUT_DIGEST, SIGNATURE_R, SIGNATURE_S
+        );
+}
+
+#[test]
+#[should_panic(expected: 'Invalid attestation')]
+fn test_set_attested_merkle_root_from_another_signer() {
+    let contract = deploy_with_signer(CAMPAIGN_ID, SIGNER + 1);
+
+    attest_first_wave(contract);
+}
+
+#[test]
+#[should_panic(expected: 'Root signer not set')]
+fn test_set_attested_merkle_root_without_signer() {
+    let contract_address = deploy_contract(CAMPAIGN_ID);
+    let contract = IClaimerDispatcher { contract_address };
+
+    attest_first_wave(contract);
+}
@@ -19,7 +19,8 @@ use super::constants::{
 fn deploy_contract() -> ContractAddress {
     let contract = snf::declare("Claimer").expect('Contract declaration failed');
 
-    let calldata: Array<felt252> = array![];
+    // the test contract owns the claimer, so it can set roots
+    let calldata: Array<felt252> = array![test_address().into(), 'carbonable'];
     let (contract_address, _) = contract.deploy(@calldata).expect('Contract deployment failed');
 
     contract_address
@@ -39,6 +40,26 @@ fn test_set_merkle_root() {
     assert_eq!(root, MERKLE_ROOT_FIRST_WAVE);
 }
 
+#[test]
+#[should_panic(expected: 'Caller is not owner')]
+fn test_set_merkle_root_from_non_owner() {
+    let contract_address = deploy_contract();
+    let contract = IClaimerDispatcher { contract_address };
+
+    start_cheat_caller_address(contract_address, contract_address_const::<0xabc>());
+    contract.set_merkle_root(MERKLE_ROOT_FIRST_WAVE);
+}
+
+#[test]
+#[should_panic(expected: 'Caller is not owner')]
+fn test_set_root_signer_from_non_owner() {
+    let contract_address = deploy_contract();
+    let contract = IClaimerDispatcher { contract_address };
+
+    start_cheat_caller_address(contract_address, contract_address_const::<0xabc>());
+    contract.set_root_signer(0x123);
+}
+
 #[test]
 fn test_bob_claims_single_allocation() {
     /// Test a simple claim scenario where Bob claims his allocation from the first wave.
@@ -197,6 +218,7 @@ fn test_alice_claims_in_second_wave() {
 
     let (new_root, alice_address, amount, timestamp, id, proof) =
         get_alice_second_wave_allocation();
+    stop_cheat_caller_address(contract_address);
     contract.set_merkle_root(new_root);
     assert!(!contract.check_claimed(alice_address, timestamp, amount, id));
 
@@ -246,7 +268,9 @@ fn test_john_claims_multiple_allocations() {
     assert!(contract.check_claimed(john_address, timestamp2, amount2, id_2));
     assert!(!contract.check_claimed(john_address, timestamp3, amount3, id_3));
 
+    stop_cheat_caller_address(contract_address);
     contract.set_merkle_root(new_root);
+    start_cheat_caller_address(contract_address, john_address);
     contract.claim(amount4, timestamp4, id_4, proof4);
     assert!(contract.check_claimed(john_address, timestamp4, amount4, id_4));
     assert!(!contract.check_claimed(john_address, timestamp3, amount3, id_3));
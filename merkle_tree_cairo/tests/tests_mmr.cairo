@@ -0,0 +1,90 @@
+use merkle_tree_cairo::mmr::{MmrHasher, bag_peaks, hash_pair, verify_mmr_proof};
+
+// Same vectors as test_roots_match_cairo in merkle_tree_rust/tests/mmr_tests.rs: the three
+// allocations of the Claimer tests appended to an empty range.
+const PEDERSEN_ROOT: felt252 = 0x1578caa6abdd4e491d7d88c1ef93ddf9a59929fdcc8d347cc76eb3c4c08c36c;
+const POSEIDON_ROOT: felt252 = 0x6532265ec84a3516941818fd81c39ef1fd656c32e8bbf7ca691e333d04c69ac;
+const LEAF_0: felt252 = 0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58;
+const LEAF_1: felt252 = 0x46f948acb5b407293e7118b3158c62228e6d94c5a563d2522bef8c589d38363;
+const LEAF_2: felt252 = 0x57d0a984fe6da387abe199923d7ab26791ac965b223a424f036ee3c901a58cb;
+
+fn pedersen_peaks() -> Array<felt252> {
+    array![0xe9fa78e0a7ef428a5f2eff184a26cfc24ff838c41dd092889844ca8fb49a57, LEAF_2]
+}
+
+fn poseidon_peaks() -> Array<felt252> {
+    array![0x601419affcace0e288c93a9e219ee53e04061704fb7d46c269afef6e41105f1, LEAF_2]
+}
+
+#[test]
+fn test_bagged_roots_match_rust() {
+    assert_eq!(bag_peaks(MmrHasher::Pedersen, 3, pedersen_peaks().span()), PEDERSEN_ROOT);
+    assert_eq!(bag_peaks(MmrHasher::Poseidon, 3, poseidon_peaks().span()), POSEIDON_ROOT);
+}
+
+#[test]
+fn test_peaks_hash_their_leaves() {
+    assert_eq!(hash_pair(MmrHasher::Pedersen, LEAF_1, LEAF_0), *pedersen_peaks().at(0));
+    assert_eq!(hash_pair(MmrHasher::Poseidon, LEAF_1, LEAF_0), *poseidon_peaks().at(0));
+}
+
+#[test]
+fn test_verify_pedersen_proof() {
+    let siblings = array![LEAF_1];
+    let peaks = pedersen_peaks();
+    let valid = verify_mmr_proof(
+        MmrHasher::Pedersen, PEDERSEN_ROOT, LEAF_0, 0, 3, siblings.span(), peaks.span()
+    );
+    assert!(valid);
+}
+
+#[test]
+fn test_verify_poseidon_proof() {
+    let siblings = array![LEAF_0];
+    let peaks = poseidon_peaks();
+    let valid = verify_mmr_proof(
+        MmrHasher::Poseidon, POSEIDON_ROOT, LEAF_1, 1, 3, siblings.span(), peaks.span()
+    );
+    assert!(valid);
+}
+
+#[test]
+fn test_verify_lone_peak() {
+    /// The last leaf is a mountain of its own, so its proof has no siblings.
+    let peaks = pedersen_peaks();
+    let valid = verify_mmr_proof(
+        MmrHasher::Pedersen, PEDERSEN_ROOT, LEAF_2, 2, 3, array![].span(), peaks.span()
+    );
+    assert!(valid);
+}
+
+#[test]
+fn test_wrong_hasher_fails() {
+    let siblings = array![LEAF_1];
+    let peaks = pedersen_peaks();
+    let valid = verify_mmr_proof(
+        MmrHasher::Poseidon, PEDERSEN_ROOT, LEAF_0, 0, 3, siblings.span(), peaks.span()
+    );
+    assert!(!valid);
+}
+
+#[test]
+fn test_wrong_leaf_count_fails() {
+    /// Four leaves make a single mountain, so two peaks cannot match.
+    let siblings = array![LEAF_1];
+    let peaks = pedersen_peaks();
+    let valid = verify_mmr_proof(
+        MmrHasher::Pedersen, PEDERSEN_ROOT, LEAF_0, 0, 4, siblings.span(), peaks.span()
+    );
+    assert!(!valid);
+}
+
+#[test]
+fn test_wrong_mountain_fails() {
+    /// The first leaf claims the position of the lone peak.
+    let peaks = pedersen_peaks();
+    let valid = verify_mmr_proof(
+        MmrHasher::Pedersen, PEDERSEN_ROOT, LEAF_0, 2, 3, array![].span(), peaks.span()
+    );
+    assert!(!valid);
+}
@@ -0,0 +1,80 @@
+use merkle_tree_cairo::sum_tree::{
+    SumNode, leaf_node, hash_sum_pair, compute_sum_root, verify_sum_proof
+};
+
+// Same vector as test_root_commits_to_total in merkle_tree_rust/tests/sum_tree_tests.rs
+const SUM_ROOT_HASH: felt252 = 0x2968157df6520f4a0156dec378e093edebb31b86a6053ab98ab59d26f7c6627;
+const SUM_ROOT_TOTAL: u128 = 600;
+
+fn sum_root() -> SumNode {
+    SumNode { hash: SUM_ROOT_HASH, sum: SUM_ROOT_TOTAL }
+}
+
+fn bob_leaf() -> SumNode {
+    leaf_node(0x1234567890abcdef1234567890abcdef12345678, 150, 2, 1)
+}
+
+fn bob_proof() -> Array<SumNode> {
+    array![
+        SumNode {
+            hash: 0x46f948acb5b407293e7118b3158c62228e6d94c5a563d2522bef8c589d38363, sum: 200
+        },
+        SumNode {
+            hash: 0x3b3487c618fed779bbd64580b85e4871c6a98250e3ac70f4b7628400ba282b0, sum: 250
+        }
+    ]
+}
+
+#[test]
+fn test_sum_root_matches_rust() {
+    let root = compute_sum_root(bob_leaf(), bob_proof().span());
+    assert_eq!(root, sum_root());
+}
+
+#[test]
+fn test_verify_padded_leaf() {
+    /// The third leaf of the tree is paired with an empty node.
+    let leaf = leaf_node(0x3F5A1E9DAB72F1A8C12D4D9B3A58A7B4425E7B4C, 250, 4, 5);
+    let proof = array![
+        SumNode { hash: 0, sum: 0 },
+        SumNode {
+            hash: 0x279b544314fe563828d947d317a576aceebcdbc199ae56d68ff87200ad48344, sum: 350
+        }
+    ];
+    assert!(verify_sum_proof(sum_root(), leaf, proof.span()));
+}
+
+#[test]
+fn test_inflated_amount_fails() {
+    let leaf = leaf_node(0x1234567890abcdef1234567890abcdef12345678, 151, 2, 1);
+    assert!(!verify_sum_proof(sum_root(), leaf, bob_proof().span()));
+}
+
+#[test]
+fn test_tampered_subtotal_fails() {
+    let proof = array![
+        SumNode {
+            hash: 0x46f948acb5b407293e7118b3158c62228e6d94c5a563d2522bef8c589d38363, sum: 199
+        },
+        SumNode {
+            hash: 0x3b3487c618fed779bbd64580b85e4871c6a98250e3ac70f4b7628400ba282b0, sum: 250
+        }
+    ];
+    assert!(!verify_sum_proof(sum_root(), bob_leaf(), proof.span()));
+}
+
+#[test]
+fn test_hash_sum_pair_is_order_independent() {
+    let a = SumNode { hash: 1, sum: 10 };
+    let b = SumNode { hash: 2, sum: 20 };
+    assert_eq!(hash_sum_pair(a, b), hash_sum_pair(b, a));
+    assert_eq!(hash_sum_pair(a, b).sum, 30);
+}
+
+#[test]
+#[should_panic(expected: ('u128_add Overflow',))]
+fn test_subtotal_overflow_panics() {
+    let a = SumNode { hash: 1, sum: 0xffffffffffffffffffffffffffffffff };
+    let b = SumNode { hash: 2, sum: 1 };
+    hash_sum_pair(a, b);
+}
@@ -0,0 +1,54 @@
+use alexandria_merkle_tree::merkle_tree::{
+    Hasher, MerkleTree, MerkleTreeImpl, pedersen::PedersenHasherImpl, MerkleTreeTrait,
+};
+use core::hash::LegacyHash;
+
+use super::test_vectors::{TEST_VECTORS_VERSION, VectorLeaf, test_vectors};
+
+fn leaf_hash(leaf: @VectorLeaf) -> felt252 {
+    /// Hashes the leaf as `Claimer::claim` does.
+    let amount_felt: felt252 = (*leaf.amount).into();
+    let timestamp_felt: felt252 = (*leaf.timestamp).into();
+    let id_felt: felt252 = (*leaf.id).into();
+
+    let intermediate_hash = LegacyHash::hash(*leaf.claimee, amount_felt);
+    let intermediate_hash = LegacyHash::hash(intermediate_hash, timestamp_felt);
+    LegacyHash::hash(intermediate_hash, id_felt)
+}
+
+#[test]
+fn test_vectors_version() {
+    assert_eq!(TEST_VECTORS_VERSION, 1);
+}
+
+#[test]
+fn test_vectors_match_rust() {
+    /// Every leaf hash and proof of the shared vectors resolves to the recorded root.
+    let mut merkle_tree: MerkleTree<Hasher> = MerkleTreeImpl::new();
+    let mut vectors = test_vectors();
+    let mut checked_leaves = 0;
+
+    loop {
+        match vectors.pop_front() {
+            Option::Some(vector) => {
+                let mut leaves = vector.leaves;
+                loop {
+                    match leaves.pop_front() {
+                        Option::Some(leaf) => {
+                            let computed_leaf = leaf_hash(@leaf);
+                            assert!(computed_leaf == leaf.leaf, "Leaf mismatch in {}", vector.name);
+
+                            let root = merkle_tree.compute_root(computed_leaf, leaf.proof.span());
+                            assert!(root == vector.root, "Root mismatch in {}", vector.name);
+                            checked_leaves += 1;
+                        },
+                        Option::None => { break; },
+                    };
+                };
+            },
+            Option::None => { break; },
+        };
+    };
+
+    assert_eq!(checked_leaves, 29);
+}
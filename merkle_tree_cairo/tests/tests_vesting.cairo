@@ -0,0 +1,177 @@
+use starknet::{ContractAddress, contract_address_const};
+use snforge_std as snf;
+use snforge_std::{
+    ContractClassTrait, test_address, spy_events, EventSpyAssertionsTrait,
+    start_cheat_caller_address, stop_cheat_caller_address, start_cheat_block_timestamp
+};
+
+use merkle_tree_cairo::vesting::{vesting_leaf, vested_amount};
+use merkle_tree_cairo::vesting_claimer::{
+    VestingClaimer, IVestingClaimerDispatcher, IVestingClaimerDispatcherTrait
+};
+
+// Same schedules as test_vesting_root_matches_cairo in merkle_tree_rust/tests/vesting_tests.rs.
+const VESTING_ROOT: felt252 = 0x1422a55da68895120d32a4426eee46d08360f002f522c7b5d6af8d078fbb35b;
+const BOB_LEAF: felt252 = 0x3bcc909343475db2e018850d1d81bf11bcfbb723eaca0e8a406e092a3635774;
+const TOTAL: u128 = 1200;
+const START: u64 = 1000;
+const CLIFF: u64 = 100;
+const DURATION: u64 = 400;
+const ID: u128 = 1;
+
+fn bob() -> ContractAddress {
+    contract_address_const::<0x1234567890abcdef1234567890abcdef12345678>()
+}
+
+fn bob_proof() -> Array<felt252> {
+    array![
+        0x7f1fa7441e3135d41f10d0c17c00ce788272fb4a3ae57cb61b2938e43c0d38,
+        0x17a4bf0e5cbdae059393f21924f6a260315024527f9b6e1c786bbcef5e23851
+    ]
+}
+
+fn deploy_contract() -> ContractAddress {
+    let contract = snf::declare("VestingClaimer").expect('Contract declaration failed');
+
+    // the test contract owns the claimer, so it can set roots
+    let calldata: Array<felt252> = array![test_address().into()];
+    let (contract_address, _) = contract.deploy(@calldata).expect('Contract deployment failed');
+
+    contract_address
+}
+
+fn deploy_for_bob(timestamp: u64) -> IVestingClaimerDispatcher {
+    let contract_address = deploy_contract();
+    let contract = IVestingClaimerDispatcher { contract_address };
+    contract.set_merkle_root(VESTING_ROOT);
+
+    start_cheat_caller_address(contract_address, bob());
+    start_cheat_block_timestamp(contract_address, timestamp);
+    contract
+}
+
+/// Sets the root as the owner, then calls as bob again.
+fn rotate_root(contract: IVestingClaimerDispatcher, root: felt252) {
+    stop_cheat_caller_address(contract.contract_address);
+    contract.set_merkle_root(root);
+    start_cheat_caller_address(contract.contract_address, bob());
+}
+
+#[test]
+fn test_vesting_leaf_matches_rust() {
+    let leaf = vesting_leaf(bob().into(), TOTAL, START, CLIFF, DURATION, ID);
+    assert_eq!(leaf, BOB_LEAF);
+}
+
+#[test]
+fn test_vested_amount() {
+    /// Same values as test_vested_amount in Rust.
+    assert_eq!(vested_amount(TOTAL, START, CLIFF, DURATION, 0), 0);
+    assert_eq!(vested_amount(TOTAL, START, CLIFF, DURATION, 1099), 0);
+    assert_eq!(vested_amount(TOTAL, START, CLIFF, DURATION, 1100), 300);
+    assert_eq!(vested_amount(TOTAL, START, CLIFF, DURATION, 1201), 603);
+    assert_eq!(vested_amount(TOTAL, START, CLIFF, DURATION, 1400), TOTAL);
+    assert_eq!(vested_amount(300, 2000, 0, 0, 2000), 300);
+}
+
+#[test]
+fn test_release_incrementally() {
+    /// The proof is checked on the first release, later releases under the same root pass an
+    /// empty one.
+    let contract = deploy_for_bob(1100);
+    let contract_address = contract.contract_address;
+
+    let mut spy = spy_events();
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, bob_proof());
+    assert_eq!(contract.get_released(bob(), TOTAL, START, CLIFF, DURATION, ID), 300);
+
+    start_cheat_block_timestamp(contract_address, 1201);
+    assert_eq!(contract.get_releasable(bob(), TOTAL, START, CLIFF, DURATION, ID), 303);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, array![]);
+    assert_eq!(contract.get_released(bob(), TOTAL, START, CLIFF, DURATION, ID), 603);
+
+    start_cheat_block_timestamp(contract_address, 5000);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, array![]);
+    assert_eq!(contract.get_released(bob(), TOTAL, START, CLIFF, DURATION, ID), TOTAL);
+    assert_eq!(contract.get_releasable(bob(), TOTAL, START, CLIFF, DURATION, ID), 0);
+
+    let first = VestingClaimer::Event::Released(
+        VestingClaimer::Released { beneficiary: bob(), id: ID, amount: 300, total_released: 300 }
+    );
+    let last = VestingClaimer::Event::Released(
+        VestingClaimer::Released { beneficiary: bob(), id: ID, amount: 597, total_released: TOTAL }
+    );
+    spy.assert_emitted(@array![(contract_address, first), (contract_address, last)]);
+}
+
+#[test]
+#[should_panic(expected: 'Nothing to release')]
+fn test_release_before_cliff() {
+    let contract = deploy_for_bob(1099);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, bob_proof());
+}
+
+#[test]
+#[should_panic(expected: 'Nothing to release')]
+fn test_release_twice_at_same_time() {
+    let contract = deploy_for_bob(1200);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, bob_proof());
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, array![]);
+}
+
+#[test]
+#[should_panic(expected: 'Invalid proof')]
+fn test_first_release_requires_proof() {
+    let contract = deploy_for_bob(1200);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, array![]);
+}
+
+#[test]
+#[should_panic(expected: 'Invalid proof')]
+fn test_release_with_larger_total() {
+    /// The schedule is part of the leaf, so it cannot be changed by the beneficiary.
+    let contract = deploy_for_bob(1200);
+    contract.release(TOTAL + 1, START, CLIFF, DURATION, ID, bob_proof());
+}
+
+#[test]
+#[should_panic(expected: 'Invalid proof')]
+fn test_release_from_another_caller() {
+    let contract = deploy_for_bob(1200);
+    start_cheat_caller_address(contract.contract_address, contract_address_const::<0xabc>());
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, bob_proof());
+}
+
+#[test]
+#[should_panic(expected: 'Invalid proof')]
+fn test_release_after_root_rotation() {
+    /// A new root revokes earlier verifications, so the schedule must be proven again.
+    let contract = deploy_for_bob(1100);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, bob_proof());
+
+    rotate_root(contract, VESTING_ROOT + 1);
+    start_cheat_block_timestamp(contract.contract_address, 1201);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, array![]);
+}
+
+#[test]
+fn test_release_after_root_rotation_with_new_proof() {
+    /// Rotating back to a root the schedule is in accepts a proof again, and keeps what was
+    /// already released.
+    let contract = deploy_for_bob(1100);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, bob_proof());
+
+    rotate_root(contract, VESTING_ROOT + 1);
+    rotate_root(contract, VESTING_ROOT);
+    start_cheat_block_timestamp(contract.contract_address, 1201);
+    contract.release(TOTAL, START, CLIFF, DURATION, ID, bob_proof());
+    assert_eq!(contract.get_released(bob(), TOTAL, START, CLIFF, DURATION, ID), 603);
+}
+
+#[test]
+#[should_panic(expected: 'Caller is not the owner')]
+fn test_set_merkle_root_from_non_owner() {
+    /// Only the owner rotates the root; bob could otherwise set one holding a larger schedule.
+    let contract = deploy_for_bob(1100);
+    contract.set_merkle_root(VESTING_ROOT + 1);
+}
@@ -0,0 +1,297 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::mmr::{MerkleMountainRange, MmrHasher};
+use merkle_tree::core::proof::{parse_proof, verify_proof};
+use merkle_tree::core::sharded::{ShardStrategy, ShardedMerkleTree};
+use merkle_tree::core::stream::stream_root;
+use merkle_tree::core::sum_tree::MerkleSumTree;
+#[cfg(feature = "evm")]
+use merkle_tree::evm::EvmMerkleTree;
+use starknet::core::types::Felt;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Tree sizes to measure, overridden with a comma-separated `MERKLE_BENCH_SIZES`.
+const DEFAULT_SIZES: &[usize] = &[1_000, 10_000];
+
+/// Sizes added with `MERKLE_BENCH_LARGE=1`.
+///
+/// `MerkleTree::new` keeps a `HashSet` of allocations per node, so these need several GiB.
+const LARGE_SIZES: &[usize] = &[100_000, 1_000_000];
+
+/// Global allocator tracking live and peak heap usage.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `f` and returns the heap it used at its peak, on top of what was live before.
+fn peak_memory<T>(f: impl FnOnce() -> T) -> usize {
+    let baseline = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    drop(black_box(f()));
+    PEAK.load(Ordering::Relaxed) - baseline
+}
+
+fn bench_sizes() -> Vec<usize> {
+    let mut sizes = match std::env::var("MERKLE_BENCH_SIZES") {
+        Ok(sizes) => sizes
+            .split(',')
+            .map(|size| size.trim().parse().expect("Invalid MERKLE_BENCH_SIZES"))
+            .collect(),
+        Err(_) => DEFAULT_SIZES.to_vec(),
+    };
+    if std::env::var_os("MERKLE_BENCH_LARGE").is_some() {
+        sizes.extend(LARGE_SIZES);
+        sizes.sort_unstable();
+        sizes.dedup();
+    }
+    sizes
+}
+
+/// Distinct allocations spread over the address space, a few per address.
+fn synthetic_allocations(count: usize) -> Vec<Allocation> {
+    (0..count as u64)
+        .map(|i| {
+            let address = Felt::from(i / 3 + 1) * Felt::from(0x9e3779b97f4a7c15u64);
+            Allocation {
+                address: format!("{:#x}", address),
+                amount: 1_000 + i % 5_000,
+                timestamp: format!("{:#x}", 1_700_000_000 + i % 3),
+                id: i,
+            }
+        })
+        .collect()
+}
+
+/// Benchmark names of the mountain range hashers.
+const MMR_HASHERS: [(&str, MmrHasher); 2] = [
+    ("mmr_pedersen", MmrHasher::Pedersen),
+    ("mmr_poseidon", MmrHasher::Poseidon),
+];
+
+fn shard_strategy(size: usize) -> ShardStrategy {
+    ShardStrategy::IndexRange {
+        size: size.div_ceil(16),
+    }
+}
+
+fn bench_build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build");
+    group.sample_size(10);
+
+    for size in bench_sizes() {
+        let allocations = synthetic_allocations(size);
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("merkle_tree", size),
+            &allocations,
+            |b, a| b.iter_batched(|| a.clone(), MerkleTree::new, BatchSize::LargeInput),
+        );
+        group.bench_with_input(BenchmarkId::new("streaming", size), &allocations, |b, a| {
+            b.iter_batched(|| a.clone(), stream_root, BatchSize::LargeInput)
+        });
+        group.bench_with_input(
+            BenchmarkId::new("sharded_16", size),
+            &allocations,
+            |b, a| {
+                b.iter_batched(
+                    || a.clone(),
+                    |a| ShardedMerkleTree::new(a, shard_strategy(size)),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("sum_tree", size), &allocations, |b, a| {
+            b.iter_batched(|| a.clone(), MerkleSumTree::new, BatchSize::LargeInput)
+        });
+        for (name, hasher) in MMR_HASHERS {
+            group.bench_with_input(BenchmarkId::new(name, size), &allocations, |b, a| {
+                b.iter_batched(
+                    || a.clone(),
+                    |a| MerkleMountainRange::from_allocations(a, hasher),
+                    BatchSize::LargeInput,
+                )
+            });
+        }
+        #[cfg(feature = "evm")]
+        group.bench_with_input(
+            BenchmarkId::new("evm_keccak", size),
+            &allocations,
+            |b, a| b.iter_batched(|| a.clone(), EvmMerkleTree::new, BatchSize::LargeInput),
+        );
+    }
+    group.finish();
+}
+
+fn bench_proofs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("proof");
+
+    for size in bench_sizes() {
+        let allocations = synthetic_allocations(size);
+        let tree = MerkleTree::new(allocations.clone());
+        let spill_dir = std::env::temp_dir().join(format!("bench_sharded_{}", size));
+        let sharded =
+            ShardedMerkleTree::with_spill(allocations.clone(), shard_strategy(size), &spill_dir)
+                .unwrap();
+        let shard_size = size.div_ceil(16);
+        let sum_tree = MerkleSumTree::new(allocations.clone()).unwrap();
+        let mmrs = MMR_HASHERS.map(|(name, hasher)| {
+            let mmr = MerkleMountainRange::from_allocations(allocations.clone(), hasher).unwrap();
+            (name, mmr)
+        });
+        #[cfg(feature = "evm")]
+        let evm_tree = EvmMerkleTree::new(allocations.clone()).unwrap();
+
+        let mut i = 0;
+        let mut next = || {
+            i = (i + 7919) % allocations.len();
+            &allocations[i]
+        };
+
+        group.bench_function(BenchmarkId::new("build_address_calldata", size), |b| {
+            b.iter(|| {
+                let a = next();
+                tree.build_address_calldata(&a.address, a.amount, &a.timestamp, a.id)
+            })
+        });
+        group.bench_function(BenchmarkId::new("sharded_16", size), |b| {
+            // Locating a leaf by value would scan the shard files, so index it directly
+            let mut j = 0;
+            b.iter(|| {
+                j = (j + 7919) % size;
+                sharded.build_proof_at(j / shard_size, j % shard_size)
+            })
+        });
+        group.bench_function(BenchmarkId::new("sum_tree", size), |b| {
+            b.iter(|| sum_tree.build_proof(next()))
+        });
+        for (name, mmr) in mmrs.iter() {
+            group.bench_function(BenchmarkId::new(*name, size), |b| {
+                b.iter(|| mmr.proof_for(next()))
+            });
+        }
+        #[cfg(feature = "evm")]
+        group.bench_function(BenchmarkId::new("evm_keccak", size), |b| {
+            b.iter(|| evm_tree.proof(next()))
+        });
+
+        let allocation = &allocations[size / 2];
+        let calldata = tree
+            .build_address_calldata(
+                &allocation.address,
+                allocation.amount,
+                &allocation.timestamp,
+                allocation.id,
+            )
+            .unwrap();
+        let proof = parse_proof(&calldata[4..]).unwrap();
+        group.bench_function(BenchmarkId::new("verify_proof", size), |b| {
+            b.iter(|| {
+                let leaf = allocation.leaf_hash().unwrap();
+                verify_proof(tree.root.value, leaf, &proof)
+            })
+        });
+
+        std::fs::remove_dir_all(&spill_dir).unwrap();
+    }
+    group.finish();
+}
+
+fn bench_merge(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merge");
+    group.sample_size(10);
+
+    for size in bench_sizes() {
+        // A second wave adding a tenth of the first one
+        let allocations = synthetic_allocations(size + size / 10);
+        let (first, second) = allocations.split_at(size);
+        let tree = MerkleTree::new(first.to_vec());
+
+        group.throughput(Throughput::Elements(allocations.len() as u64));
+        group.bench_function(BenchmarkId::new("merge_merkle_trees", size), |b| {
+            b.iter_batched(
+                || second.to_vec(),
+                |second| tree.merge_merkle_trees(second),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// Prints the peak heap used by each builder; criterion only reports time.
+fn report_peak_memory(_: &mut Criterion) {
+    for size in bench_sizes() {
+        let allocations = synthetic_allocations(size);
+        let mut builders: Vec<(&str, usize)> = vec![
+            (
+                "merkle_tree",
+                peak_memory(|| MerkleTree::new(allocations.clone())),
+            ),
+            (
+                "streaming",
+                peak_memory(|| stream_root(allocations.iter().cloned())),
+            ),
+            (
+                "sharded_16",
+                peak_memory(|| ShardedMerkleTree::new(allocations.clone(), shard_strategy(size))),
+            ),
+            (
+                "sum_tree",
+                peak_memory(|| MerkleSumTree::new(allocations.clone())),
+            ),
+        ];
+        for (name, hasher) in MMR_HASHERS {
+            builders.push((
+                name,
+                peak_memory(|| MerkleMountainRange::from_allocations(allocations.clone(), hasher)),
+            ));
+        }
+        #[cfg(feature = "evm")]
+        builders.push((
+            "evm_keccak",
+            peak_memory(|| EvmMerkleTree::new(allocations.clone())),
+        ));
+
+        for (builder, bytes) in builders {
+            println!(
+                "memory/{}/{}: peak {:.1} MiB",
+                builder,
+                size,
+                bytes as f64 / (1024.0 * 1024.0)
+            );
+        }
+    }
+}
+
+criterion_group!(
+    benches,
+    report_peak_memory,
+    bench_build,
+    bench_proofs,
+    bench_merge
+);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::proof::{parse_proof, verify_proof};
+
+/// Keeps trees small enough for the fuzzer to stay fast.
+const MAX_ALLOCATIONS: usize = 64;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(allocations) = serde_json::from_slice::<Vec<Allocation>>(data) else {
+        return;
+    };
+
+    // `MerkleTree::new` expects validated allocations, as the CLI and bindings check them first
+    if allocations.is_empty()
+        || allocations.len() > MAX_ALLOCATIONS
+        || allocations.iter().any(|a| a.to_felts().is_err())
+    {
+        return;
+    }
+
+    let tree = MerkleTree::new(allocations.clone());
+    for allocation in allocations.iter() {
+        let calldata = tree
+            .build_address_calldata(
+                &allocation.address,
+                allocation.amount,
+                &allocation.timestamp,
+                allocation.id,
+            )
+            .unwrap();
+        let proof = parse_proof(&calldata[4..]).unwrap();
+        assert!(verify_proof(
+            tree.root.value,
+            allocation.leaf_hash().unwrap(),
+            &proof
+        ));
+    }
+});
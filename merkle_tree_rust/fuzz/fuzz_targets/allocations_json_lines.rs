@@ -0,0 +1,28 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::stream::{read_json_lines, StreamingTreeBuilder};
+use std::io::Cursor;
+
+/// Keeps trees small enough for the fuzzer to stay fast.
+const MAX_ALLOCATIONS: usize = 64;
+
+fuzz_target!(|data: &[u8]| {
+    let mut allocations = vec![];
+    let mut builder = StreamingTreeBuilder::new();
+
+    for allocation in read_json_lines(Cursor::new(data)).take(MAX_ALLOCATIONS) {
+        let Ok(allocation) = allocation else {
+            continue;
+        };
+        if builder.push(&allocation).is_ok() {
+            allocations.push(allocation);
+        }
+    }
+
+    match builder.finalize() {
+        Ok(root) => assert_eq!(root, MerkleTree::new(allocations).root.value),
+        Err(_) => assert!(allocations.is_empty()),
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use merkle_tree::core::proof::{compute_root, parse_proof, verify_proof};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    // A leaf followed by its proof, as comma-separated hex strings
+    let hashes: Vec<String> = input.split(',').map(str::to_string).collect();
+    let Ok(hashes) = parse_proof(&hashes) else {
+        return;
+    };
+    let (leaf, proof) = hashes.split_first().unwrap();
+
+    let root = compute_root(*leaf, proof);
+    assert!(verify_proof(root, *leaf, proof));
+
+    let hex: Vec<String> = hashes.iter().map(|hash| format!("{:#x}", hash)).collect();
+    assert_eq!(parse_proof(&hex).unwrap(), hashes);
+});
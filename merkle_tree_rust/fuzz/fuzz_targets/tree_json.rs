@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use merkle_tree::core::merkle_tree::MerkleTree;
+
+fuzz_target!(|data: &[u8]| {
+    // Deserialization rebuilds the tree, so bound the input size
+    if data.len() > 16 * 1024 {
+        return;
+    }
+    let Ok(tree) = serde_json::from_slice::<MerkleTree>(data) else {
+        return;
+    };
+
+    let json = serde_json::to_string(&tree).unwrap();
+    let reloaded: MerkleTree = serde_json::from_str(&json).unwrap();
+    assert_eq!(reloaded.root.value, tree.root.value);
+    assert_eq!(reloaded.get_allocations(), tree.get_allocations());
+});
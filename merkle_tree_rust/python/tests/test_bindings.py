@@ -0,0 +1,75 @@
+"""Tests for the Python bindings.
+
+Build them with `maturin develop` (or `pip install .`) from merkle_tree_rust, then run
+`python3 -m unittest discover python/tests`.
+"""
+
+import json
+import os
+import unittest
+
+from merkle_tree import Allocation, MerkleTree, compute_root, verify_proof
+
+DATA_DIR = os.path.join(os.path.dirname(__file__), "..", "..", "data")
+OUTPUT_DIR = os.path.join(os.path.dirname(__file__), "..", "..", "output")
+
+
+def load_allocations(filename):
+    with open(os.path.join(DATA_DIR, filename)) as f:
+        return [Allocation(**item) for item in json.load(f)]
+
+
+def expected_root(filename):
+    with open(os.path.join(OUTPUT_DIR, filename)) as f:
+        return int(f.readline().split(":")[1])
+
+
+class TestMerkleTree(unittest.TestCase):
+    def test_root_matches_rust_output(self):
+        tree = MerkleTree(load_allocations("mock_allocations_first_wave.json"))
+        self.assertEqual(int(tree.root, 16), expected_root("first_merkle_tree_data.txt"))
+
+    def test_merged_root_matches_rust_output(self):
+        tree = MerkleTree(load_allocations("mock_allocations_first_wave.json"))
+        merged = tree.merge_merkle_trees(load_allocations("mock_allocations_second_wave.json"))
+        self.assertEqual(int(merged.root, 16), expected_root("second_merkle_tree_data.txt"))
+
+    def test_calldata_proofs_verify(self):
+        allocations = load_allocations("mock_allocations_first_wave.json")
+        tree = MerkleTree(allocations)
+
+        for allocation in allocations:
+            calldata = tree.build_address_calldata(
+                allocation.address, allocation.amount, allocation.timestamp, allocation.id
+            )
+            proof = calldata[4:]
+            self.assertTrue(verify_proof(tree.root, allocation, proof))
+            self.assertEqual(compute_root(allocation.leaf_hash(), proof), tree.root)
+
+    def test_proofs_for_address(self):
+        tree = MerkleTree(load_allocations("mock_allocations_second_wave.json"))
+        address = "0x7897897897897897897897897897897897897890"
+
+        self.assertEqual(len(tree.allocations_for(address)), 2)
+        self.assertEqual(len(tree.proofs_for(address)), 2)
+
+    def test_json_round_trip(self):
+        tree = MerkleTree(load_allocations("mock_allocations_second_wave.json"))
+        restored = MerkleTree.from_json(tree.to_json())
+
+        self.assertEqual(restored.root, tree.root)
+        self.assertEqual(restored.allocations(), tree.allocations())
+
+    def test_invalid_input_raises(self):
+        with self.assertRaises(ValueError):
+            MerkleTree([Allocation("0x1234567dhiodhaoo", 150, "0x2", 1)])
+        with self.assertRaises(ValueError):
+            MerkleTree([])
+
+        tree = MerkleTree(load_allocations("mock_allocations_first_wave.json"))
+        with self.assertRaises(ValueError):
+            tree.build_address_calldata("0xdead", 1, "0x1", 1)
+
+
+if __name__ == "__main__":
+    unittest.main()
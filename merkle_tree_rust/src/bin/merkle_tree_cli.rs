@@ -0,0 +1,228 @@
+use merkle_tree::core::aggregate::{aggregate, AggregationKey};
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::attestation::RootAttestation;
+use merkle_tree::core::ids::{assign_ids, IdMode};
+use merkle_tree::core::manifest::BuildManifest;
+use merkle_tree::core::merkle_tree::{LeafOrdering, MerkleTree};
+use merkle_tree::core::stream::{read_json_lines, SpilledLevels, StreamingTreeBuilder};
+use serde_json::Value;
+use starknet::core::types::Felt;
+use std::env;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::Path;
+use std::process;
+
+const USAGE: &str = "Usage:
+  merkle_tree_cli diff <old.json> <new.json>
+  merkle_tree_cli root <allocations.jsonl> [spill_dir]
+  merkle_tree_cli proof <spill_dir> <leaf_index>
+  merkle_tree_cli standard <input.json>
+  merkle_tree_cli attest <allocations.json> <campaign_id> <wave> --manifest <manifest.json>
+  merkle_tree_cli verify-attestation <attestation.json> [signer]
+  merkle_tree_cli manifest <allocations.json> [ordering]
+  merkle_tree_cli reproduce <allocations.json> <manifest.json>
+  merkle_tree_cli aggregate <rows.json> [address|address_timestamp] [previous.json]
+  merkle_tree_cli ids <allocations.json> [mode] [previous.json]
+
+diff files are either a list of allocations or a serialized MerkleTree.
+root streams one JSON allocation per line, optionally spilling level hashes for proof.
+standard prints a tree as an OpenZeppelin StandardMerkleTree dump.
+attest checks the allocations against a manifest and signs the root and its input digest
+with the Stark private key in MERKLE_SIGNER_KEY.
+manifest prints the build manifest of a tree; ordering is insertion, leaf_hash or
+address_timestamp_id. reproduce rebuilds a tree and checks it against a manifest.
+aggregate sums rows per key and prints the leaves with the rows behind each one.
+ids prints the allocations with new ids; mode is per_address, global or content_hash.
+With a previous tree, aggregate and ids continue after its ids and reject published leaves.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["diff", old, new] => diff(old, new),
+        ["root", input] => root(input, None),
+        ["root", input, spill_dir] => root(input, Some(spill_dir)),
+        ["proof", spill_dir, index] => proof(spill_dir, index),
+        ["standard", input] => standard(input),
+        ["attest", input, campaign_id, wave, "--manifest", manifest] => {
+            attest(input, campaign_id, wave, manifest)
+        }
+        ["verify-attestation", attestation] => verify_attestation(attestation, None),
+        ["verify-attestation", attestation, signer] => {
+            verify_attestation(attestation, Some(signer))
+        }
+        ["manifest", input] => manifest(input, "insertion"),
+        ["manifest", input, ordering] => manifest(input, ordering),
+        ["reproduce", input, manifest] => reproduce(input, manifest),
+        ["aggregate", input] => aggregate_rows(input, "address", None),
+        ["aggregate", input, key] => aggregate_rows(input, key, None),
+        ["aggregate", input, key, previous] => aggregate_rows(input, key, Some(previous)),
+        ["ids", input] => ids(input, "per_address", None),
+        ["ids", input, mode] => ids(input, mode, None),
+        ["ids", input, mode, previous] => ids(input, mode, Some(previous)),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Prints the allocations added, removed and modified between two trees as JSON.
+fn diff(old: &str, new: &str) -> Result<String, String> {
+    let old = load_tree(old)?;
+    let new = load_tree(new)?;
+    serde_json::to_string_pretty(&old.diff(&new)).map_err(|e| e.to_string())
+}
+
+/// Streams the root of a JSON-lines allocation file without loading it in memory.
+fn root(input: &str, spill_dir: Option<&str>) -> Result<String, String> {
+    let file = File::open(input).map_err(|e| format!("Failed to read {}: {}", input, e))?;
+    let mut builder = match spill_dir {
+        Some(dir) => StreamingTreeBuilder::with_spill(Path::new(dir))?,
+        None => StreamingTreeBuilder::new(),
+    };
+    for allocation in read_json_lines(BufReader::new(file)) {
+        builder.push(&allocation?)?;
+    }
+    Ok(format!("{:#x}", builder.finalize()?))
+}
+
+/// Prints the proof of a leaf from the level hashes spilled by `root`.
+fn proof(spill_dir: &str, index: &str) -> Result<String, String> {
+    let index = index.parse().map_err(|_| "Invalid leaf index")?;
+    let proof = SpilledLevels::open(Path::new(spill_dir))?.proof(index)?;
+    let proof: Vec<String> = proof.iter().map(|hash| format!("{:#x}", hash)).collect();
+    serde_json::to_string_pretty(&proof).map_err(|e| e.to_string())
+}
+
+/// Prints a tree as an OpenZeppelin `StandardMerkleTree` dump.
+fn standard(input: &str) -> Result<String, String> {
+    let dump = load_tree(input)?.to_standard_dump()?;
+    serde_json::to_string_pretty(&dump).map_err(|e| e.to_string())
+}
+
+/// Prints a signed `RootAttestation` of a tree's root as JSON.
+///
+/// The tree is rebuilt as the manifest describes, so the signed digest is the one of its input.
+fn attest(input: &str, campaign_id: &str, wave: &str, manifest: &str) -> Result<String, String> {
+    let private_key = env::var("MERKLE_SIGNER_KEY").map_err(|_| "MERKLE_SIGNER_KEY is not set")?;
+    let private_key = Felt::from_hex(&private_key).map_err(|_| "Invalid MERKLE_SIGNER_KEY")?;
+    let campaign_id = Felt::from_hex(campaign_id).map_err(|_| "Invalid campaign id")?;
+    let wave = wave.parse().map_err(|_| "Invalid wave")?;
+    let manifest = load_manifest(manifest)?;
+
+    let root = manifest.reproduce(load_allocations(input)?)?.root.value;
+    let input_digest = manifest.input_digest;
+    let attestation = RootAttestation::sign(&private_key, campaign_id, wave, root, input_digest)?;
+    serde_json::to_string_pretty(&attestation).map_err(|e| e.to_string())
+}
+
+/// Checks the signature of an attestation, and its signer when one is given.
+fn verify_attestation(path: &str, signer: Option<&str>) -> Result<String, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let attestation: RootAttestation =
+        serde_json::from_str(&data).map_err(|e| format!("Invalid attestation {}: {}", path, e))?;
+
+    match signer {
+        Some(signer) => {
+            let signer = Felt::from_hex(signer).map_err(|_| "Invalid signer")?;
+            attestation.verify_signer(&signer)?
+        }
+        None => attestation.verify()?,
+    }
+    Ok(format!(
+        "Valid attestation of root {:#x} by {:#x}",
+        attestation.root, attestation.signer
+    ))
+}
+
+/// Builds a tree from an allocation list and prints its manifest.
+fn manifest(input: &str, ordering: &str) -> Result<String, String> {
+    let ordering: LeafOrdering = serde_json::from_value(Value::String(ordering.to_string()))
+        .map_err(|_| format!("Invalid ordering: {}", ordering))?;
+    let (_, manifest) = BuildManifest::build(load_allocations(input)?, ordering)?;
+    serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())
+}
+
+/// Rebuilds a tree from an allocation list and confirms it matches a manifest.
+fn reproduce(input: &str, manifest: &str) -> Result<String, String> {
+    let manifest = load_manifest(manifest)?;
+
+    let tree = manifest.reproduce(load_allocations(input)?)?;
+    let mut output = format!("Reproduced root {:#x}", tree.root.value);
+    let version = env!("CARGO_PKG_VERSION");
+    if manifest.crate_version != version {
+        output.push_str(&format!(
+            " (built with {}, checked with {})",
+            manifest.crate_version, version
+        ));
+    }
+    Ok(output)
+}
+
+/// Groups raw rows into leaves and prints the mapping report.
+fn aggregate_rows(input: &str, key: &str, previous: Option<&str>) -> Result<String, String> {
+    let key: AggregationKey = serde_json::from_value(Value::String(key.to_string()))
+        .map_err(|_| format!("Invalid aggregation key: {}", key))?;
+    let previous = previous.map(load_tree).transpose()?;
+    let report = aggregate(&load_allocations(input)?, key, previous.as_ref())?;
+    serde_json::to_string_pretty(&report).map_err(|e| e.to_string())
+}
+
+/// Assigns ids to an allocation list, optionally after a published tree.
+fn ids(input: &str, mode: &str, previous: Option<&str>) -> Result<String, String> {
+    let mode: IdMode = serde_json::from_value(Value::String(mode.to_string()))
+        .map_err(|_| format!("Invalid id mode: {}", mode))?;
+    let previous = previous.map(load_tree).transpose()?;
+    let allocations = assign_ids(load_allocations(input)?, mode, previous.as_ref())?;
+    serde_json::to_string_pretty(&allocations).map_err(|e| e.to_string())
+}
+
+/// Loads a tree from an allocation list or from a serialized `MerkleTree`.
+fn load_tree(path: &str) -> Result<MerkleTree, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let json: Value =
+        serde_json::from_str(&data).map_err(|e| format!("Failed to parse {}: {}", path, e))?;
+
+    if !json.is_array() {
+        return serde_json::from_value(json).map_err(|e| format!("Invalid tree {}: {}", path, e));
+    }
+    Ok(MerkleTree::new(parse_allocations(path, json)?))
+}
+
+fn load_manifest(path: &str) -> Result<BuildManifest, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    serde_json::from_str(&data).map_err(|e| format!("Invalid manifest {}: {}", path, e))
+}
+
+/// Loads a non-empty list of valid allocations.
+fn load_allocations(path: &str) -> Result<Vec<Allocation>, String> {
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let json: Value =
+        serde_json::from_str(&data).map_err(|e| format!("Failed to parse {}: {}", path, e))?;
+    parse_allocations(path, json)
+}
+
+fn parse_allocations(path: &str, json: Value) -> Result<Vec<Allocation>, String> {
+    let allocations: Vec<Allocation> =
+        serde_json::from_value(json).map_err(|e| format!("Invalid allocations {}: {}", path, e))?;
+    if allocations.is_empty() {
+        return Err(format!("No allocations in {}", path));
+    }
+    for allocation in allocations.iter() {
+        allocation
+            .to_felts()
+            .map_err(|e| format!("Invalid allocation in {}: {}", path, e))?;
+    }
+    Ok(allocations)
+}
@@ -0,0 +1,27 @@
+use merkle_tree::server::ProofServer;
+use std::env;
+use std::path::Path;
+use std::process;
+
+const DEFAULT_ADDR: &str = "127.0.0.1:8080";
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 || args.len() > 3 {
+        eprintln!("Usage: proof_server <campaign_dir> [address]");
+        process::exit(1);
+    }
+
+    let addr = args.get(2).map(String::as_str).unwrap_or(DEFAULT_ADDR);
+    let proofs = ProofServer::load_dir(Path::new(&args[1])).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let server = proofs.bind(addr).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    println!("Serving proofs on http://{}", addr);
+    server.run();
+}
@@ -0,0 +1,118 @@
+use crate::core::allocation::Allocation;
+use crate::core::ids::{assign_ids, IdMode};
+use crate::core::merkle_tree::MerkleTree;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use std::collections::BTreeMap;
+
+/// Fields by which raw rows are grouped into a single leaf.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregationKey {
+    /// One leaf per address, dated at its latest timestamp.
+    #[default]
+    Address,
+    /// One leaf per address and timestamp.
+    AddressTimestamp,
+}
+
+/// A final leaf and the raw rows summed into it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AggregatedLeaf {
+    pub allocation: Allocation,
+    /// Positions of the rows in the input, ascending.
+    pub rows: Vec<usize>,
+}
+
+/// Result of `aggregate`: the leaves to build the tree from and where each row went.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AggregationReport {
+    pub key: AggregationKey,
+    pub row_count: usize,
+    pub leaves: Vec<AggregatedLeaf>,
+    /// Index in `leaves` of the leaf each row was summed into, by row.
+    pub row_leaves: Vec<usize>,
+}
+
+impl AggregationReport {
+    /// Returns the allocations to pass to `MerkleTree::new`.
+    pub fn allocations(&self) -> Vec<Allocation> {
+        self.leaves
+            .iter()
+            .map(|leaf| leaf.allocation.clone())
+            .collect()
+    }
+
+    /// Returns the leaf a raw row was summed into.
+    pub fn leaf_for_row(&self, row: usize) -> Option<&AggregatedLeaf> {
+        self.leaves.get(*self.row_leaves.get(row)?)
+    }
+}
+
+/// Groups rows by key and sums their amounts.
+///
+/// Addresses and timestamps are compared as felts, so differently spelled rows still merge.
+/// Leaves are sorted by address then timestamp and numbered with `IdMode::PerAddress`, the
+/// convention of the mock allocation files, so the output does not depend on row order.
+/// Input ids are dropped; with a `previous` wave, they continue after its own as in `assign_ids`.
+pub fn aggregate(
+    rows: &[Allocation],
+    key: AggregationKey,
+    previous: Option<&MerkleTree>,
+) -> Result<AggregationReport, String> {
+    let mut groups: BTreeMap<(Felt, Felt), (u64, Vec<usize>)> = BTreeMap::new();
+    let mut latest: BTreeMap<Felt, Felt> = BTreeMap::new();
+
+    for (row, allocation) in rows.iter().enumerate() {
+        let (address, _, timestamp, _) = allocation
+            .to_felts()
+            .map_err(|e| format!("Invalid row {}: {}", row, e))?;
+        let group_timestamp = match key {
+            AggregationKey::Address => Felt::ZERO,
+            AggregationKey::AddressTimestamp => timestamp,
+        };
+
+        let (amount, group_rows) = groups.entry((address, group_timestamp)).or_default();
+        *amount = amount
+            .checked_add(allocation.amount)
+            .ok_or(format!("Amount overflow for address {:#x}", address))?;
+        group_rows.push(row);
+
+        let latest = latest.entry(address).or_insert(timestamp);
+        *latest = (*latest).max(timestamp);
+    }
+
+    let mut leaves: Vec<AggregatedLeaf> = vec![];
+    let mut row_leaves = vec![0; rows.len()];
+    for ((address, timestamp), (amount, group_rows)) in groups {
+        for row in group_rows.iter() {
+            row_leaves[*row] = leaves.len();
+        }
+        let timestamp = match key {
+            AggregationKey::Address => latest[&address],
+            AggregationKey::AddressTimestamp => timestamp,
+        };
+        leaves.push(AggregatedLeaf {
+            allocation: Allocation {
+                address: format!("{:#x}", address),
+                amount,
+                timestamp: format!("{:#x}", timestamp),
+                id: 0,
+            },
+            rows: group_rows,
+        });
+    }
+
+    let allocations = leaves.iter().map(|leaf| leaf.allocation.clone()).collect();
+    let numbered = assign_ids(allocations, IdMode::PerAddress, previous)?;
+    for (leaf, allocation) in leaves.iter_mut().zip(numbered) {
+        leaf.allocation = allocation;
+    }
+
+    Ok(AggregationReport {
+        key,
+        row_count: rows.len(),
+        leaves,
+        row_leaves,
+    })
+}
@@ -1,8 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use starknet::core::types::Felt;
+use starknet_crypto::pedersen_hash;
 use std::hash::{Hash, Hasher};
 
-#[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct Allocation {
     pub address: String,
     pub amount: u64,
@@ -29,6 +30,15 @@ impl Allocation {
 
         Ok((felt_address, felt_amount, felt_timestamp, felt_id))
     }
+
+    /// Computes the leaf hash checked by the `Claimer` contract.
+    pub fn leaf_hash(&self) -> Result<Felt, String> {
+        let (address, amount, timestamp, id) = self.to_felts()?;
+
+        let intermediate_hash = pedersen_hash(&address, &amount);
+        let intermediate_hash = pedersen_hash(&intermediate_hash, &timestamp);
+        Ok(pedersen_hash(&intermediate_hash, &id))
+    }
 }
 
 /// Utility function to convert a u64 to a Felt.
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use starknet::core::crypto::compute_hash_on_elements;
+use starknet::core::types::Felt;
+use starknet_crypto::{get_public_key, rfc6979_generate_k, sign, verify, SignError};
+
+/// Statement, signed with a Stark key, that `signer` produced `root` for a campaign wave from
+/// the input identified by `input_digest`.
+///
+/// The signed message is `compute_hash_on_elements([campaign_id, wave, root, input_digest])`,
+/// which `merkle_tree_cairo::attestation` recomputes before `check_ecdsa_signature`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RootAttestation {
+    pub campaign_id: Felt,
+    pub wave: u64,
+    pub root: Felt,
+    pub input_digest: Felt,
+    /// Stark public key of the signer.
+    pub signer: Felt,
+    pub signature_r: Felt,
+    pub signature_s: Felt,
+}
+
+/// Hash signed by a `RootAttestation`.
+pub fn attestation_hash(campaign_id: Felt, wave: u64, root: Felt, input_digest: Felt) -> Felt {
+    compute_hash_on_elements(&[campaign_id, Felt::from(wave), root, input_digest])
+}
+
+impl RootAttestation {
+    /// Signs a root with a deterministic RFC 6979 nonce.
+    pub fn sign(
+        private_key: &Felt,
+        campaign_id: Felt,
+        wave: u64,
+        root: Felt,
+        input_digest: Felt,
+    ) -> Result<Self, String> {
+        let message = attestation_hash(campaign_id, wave, root, input_digest);
+
+        // A nonce can be rejected by the curve arithmetic; retry with the next seed as
+        // starknet-rs does
+        let mut seed = None;
+        let signature = loop {
+            let k = rfc6979_generate_k(&message, private_key, seed.as_ref());
+            match sign(private_key, &message, &k) {
+                Ok(signature) => break signature,
+                Err(SignError::InvalidK) => seed = Some(seed.unwrap_or(Felt::ZERO) + Felt::ONE),
+                Err(e) => return Err(format!("Failed to sign: {}", e)),
+            }
+        };
+
+        Ok(RootAttestation {
+            campaign_id,
+            wave,
+            root,
+            input_digest,
+            signer: get_public_key(private_key),
+            signature_r: signature.r,
+            signature_s: signature.s,
+        })
+    }
+
+    /// Returns the hash the signature covers, as `attestation_hash` of the fields.
+    pub fn message_hash(&self) -> Felt {
+        attestation_hash(self.campaign_id, self.wave, self.root, self.input_digest)
+    }
+
+    /// Checks the signature against the public key the attestation names.
+    pub fn verify(&self) -> Result<(), String> {
+        let valid = verify(
+            &self.signer,
+            &self.message_hash(),
+            &self.signature_r,
+            &self.signature_s,
+        )
+        .map_err(|e| format!("Invalid signature: {}", e))?;
+
+        if !valid {
+            return Err("Invalid signature".to_string());
+        }
+        Ok(())
+    }
+
+    /// Checks that the attestation is signed by an expected public key.
+    pub fn verify_signer(&self, signer: &Felt) -> Result<(), String> {
+        if self.signer != *signer {
+            return Err(format!("Unexpected signer: {:#x}", self.signer));
+        }
+        self.verify()
+    }
+}
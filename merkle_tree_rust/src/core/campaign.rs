@@ -0,0 +1,153 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// One published root: the allocations added in the wave and the root of the cumulative tree.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Wave {
+    pub index: usize,
+    pub label: String,
+    /// Unix timestamp, in seconds, at which the wave was built.
+    pub created_at: u64,
+    pub input: Vec<Allocation>,
+    pub root: String,
+    pub leaf_count: usize,
+}
+
+/// History of the waves of a distribution, each building on the allocations of the previous ones.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Campaign {
+    pub id: String,
+    waves: Vec<Wave>,
+}
+
+impl Campaign {
+    /// Creates a campaign with no waves.
+    pub fn new(id: &str) -> Self {
+        Campaign {
+            id: id.to_string(),
+            waves: vec![],
+        }
+    }
+
+    /// Loads a campaign saved with `save`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let data =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        serde_json::from_str(&data).map_err(|e| format!("Failed to parse campaign: {}", e))
+    }
+
+    /// Writes the campaign history as JSON.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let data = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, data).map_err(|e| format!("Failed to write {:?}: {}", path, e))
+    }
+
+    /// Returns the waves in publication order.
+    pub fn waves(&self) -> &[Wave] {
+        &self.waves
+    }
+
+    /// Returns the most recent wave, whose root is the one to set on the `Claimer`.
+    pub fn latest(&self) -> Option<&Wave> {
+        self.waves.last()
+    }
+
+    /// Adds a wave, timestamped with the current time.
+    pub fn add_wave(&mut self, label: &str, allocations: Vec<Allocation>) -> Result<&Wave, String> {
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| e.to_string())?
+            .as_secs();
+        self.add_wave_at(label, allocations, created_at)
+    }
+
+    /// Adds a wave whose tree holds every allocation published so far plus the new ones.
+    pub fn add_wave_at(
+        &mut self,
+        label: &str,
+        allocations: Vec<Allocation>,
+        created_at: u64,
+    ) -> Result<&Wave, String> {
+        if allocations.is_empty() {
+            return Err("Wave has no allocations".to_string());
+        }
+        for allocation in allocations.iter() {
+            allocation.to_felts()?;
+        }
+
+        let mut cumulative = self.cumulative_allocations(self.waves.len());
+        cumulative.extend(allocations.iter().cloned());
+        let tree = MerkleTree::new(cumulative);
+
+        self.waves.push(Wave {
+            index: self.waves.len(),
+            label: label.to_string(),
+            created_at,
+            input: allocations,
+            root: format!("{:#x}", tree.root.value),
+            leaf_count: tree.get_allocations().len(),
+        });
+
+        Ok(self.waves.last().unwrap())
+    }
+
+    /// Returns the allocations of a wave that no earlier wave published.
+    pub fn new_allocations(&self, wave: usize) -> Result<Vec<&Allocation>, String> {
+        let current = self.wave(wave)?;
+        let published: HashSet<&Allocation> = self.waves[..wave]
+            .iter()
+            .flat_map(|wave| wave.input.iter())
+            .collect();
+
+        Ok(current
+            .input
+            .iter()
+            .filter(|allocation| !published.contains(allocation))
+            .collect())
+    }
+
+    /// Rebuilds the tree of a wave from the inputs of that wave and all earlier ones.
+    pub fn tree_at(&self, wave: usize) -> Result<MerkleTree, String> {
+        self.wave(wave)?;
+        Ok(MerkleTree::new(self.cumulative_allocations(wave + 1)))
+    }
+
+    /// Recomputes the root of a wave from its inputs.
+    pub fn regenerate_root(&self, wave: usize) -> Result<Felt, String> {
+        Ok(self.tree_at(wave)?.root.value)
+    }
+
+    /// Checks that every recorded root is reproduced by rebuilding its wave.
+    pub fn verify_history(&self) -> Result<(), String> {
+        for wave in self.waves.iter() {
+            let root = format!("{:#x}", self.regenerate_root(wave.index)?);
+            if root != wave.root {
+                return Err(format!(
+                    "Wave {} root mismatch: recorded {}, rebuilt {}",
+                    wave.index, wave.root, root
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn wave(&self, wave: usize) -> Result<&Wave, String> {
+        self.waves
+            .get(wave)
+            .ok_or(format!("Wave {} does not exist", wave))
+    }
+
+    /// Concatenates the inputs of the first `count` waves, as `merge_merkle_trees` does.
+    fn cumulative_allocations(&self, count: usize) -> Vec<Allocation> {
+        self.waves[..count]
+            .iter()
+            .flat_map(|wave| wave.input.iter().cloned())
+            .collect()
+    }
+}
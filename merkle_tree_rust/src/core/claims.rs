@@ -0,0 +1,147 @@
+use crate::core::allocation::{u64_to_felt, Allocation};
+use crate::core::merkle_tree::MerkleTree;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// A `Claimed` event emitted by the `Claimer` contract, as exported by an indexer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClaimedEvent {
+    pub claimee: String,
+    pub amount: u64,
+    pub timestamp: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<String>,
+}
+
+/// Summary of the claim state of a tree.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ClaimReport {
+    pub root: String,
+    pub claimed: usize,
+    pub unclaimed: usize,
+    pub unclaimed_total: u128,
+    pub unclaimed_totals: BTreeMap<String, u128>,
+    pub unmatched_events: Vec<ClaimedEvent>,
+}
+
+/// Leaves are matched on the fields a `Claimed` event carries: address, amount and timestamp.
+type EventKey = (Felt, Felt, Felt);
+
+/// Tracks which leaves of a tree have been claimed on chain.
+pub struct ClaimTracker<'a> {
+    tree: &'a MerkleTree,
+    claimed: Vec<bool>,
+    leaves_by_key: HashMap<EventKey, Vec<usize>>,
+    unmatched: Vec<ClaimedEvent>,
+}
+
+impl<'a> ClaimTracker<'a> {
+    /// Starts tracking a tree with every leaf unclaimed.
+    pub fn new(tree: &'a MerkleTree) -> Self {
+        let mut leaves_by_key: HashMap<EventKey, Vec<usize>> = HashMap::new();
+        for (i, allocation) in tree.get_allocations().iter().enumerate() {
+            if let Ok((address, amount, timestamp, _)) = allocation.to_felts() {
+                leaves_by_key
+                    .entry((address, amount, timestamp))
+                    .or_default()
+                    .push(i);
+            }
+        }
+
+        ClaimTracker {
+            tree,
+            claimed: vec![false; tree.get_allocations().len()],
+            leaves_by_key,
+            unmatched: vec![],
+        }
+    }
+
+    /// Reads a JSON array of `Claimed` events.
+    pub fn load_events(path: &Path) -> Result<Vec<ClaimedEvent>, String> {
+        let data =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        serde_json::from_str(&data).map_err(|e| format!("Failed to parse events: {}", e))
+    }
+
+    /// Marks the leaf behind each event as claimed.
+    ///
+    /// Events do not carry the allocation id, so an event claims the first unclaimed leaf with
+    /// the same address, amount and timestamp. Events matching no such leaf are kept aside.
+    pub fn ingest(&mut self, events: impl IntoIterator<Item = ClaimedEvent>) {
+        for event in events {
+            let leaf = event_key(&event)
+                .and_then(|key| self.leaves_by_key.get(&key))
+                .and_then(|leaves| leaves.iter().copied().find(|&i| !self.claimed[i]));
+
+            match leaf {
+                Some(i) => self.claimed[i] = true,
+                None => self.unmatched.push(event),
+            }
+        }
+    }
+
+    /// Returns whether the allocation at a position of the tree has been claimed.
+    pub fn is_claimed(&self, index: usize) -> bool {
+        self.claimed.get(index).copied().unwrap_or(false)
+    }
+
+    /// Returns the allocations claimed so far.
+    pub fn claimed_allocations(&self) -> Vec<&Allocation> {
+        self.allocations_where(true)
+    }
+
+    /// Returns the allocations not claimed yet, to roll over into the next wave.
+    pub fn unclaimed_allocations(&self) -> Vec<&Allocation> {
+        self.allocations_where(false)
+    }
+
+    /// Sums the unclaimed amounts of each address, as `u128` so large files cannot overflow.
+    pub fn unclaimed_totals(&self) -> BTreeMap<String, u128> {
+        let mut totals = BTreeMap::new();
+        for allocation in self.unclaimed_allocations() {
+            let address = match Felt::from_hex(&allocation.address) {
+                Ok(address) => format!("{:#x}", address),
+                Err(_) => allocation.address.clone(),
+            };
+            *totals.entry(address).or_insert(0) += allocation.amount as u128;
+        }
+        totals
+    }
+
+    /// Returns the events that matched no unclaimed leaf.
+    pub fn unmatched(&self) -> &[ClaimedEvent] {
+        &self.unmatched
+    }
+
+    /// Summarizes the claim state.
+    pub fn report(&self) -> ClaimReport {
+        let unclaimed_totals = self.unclaimed_totals();
+        ClaimReport {
+            root: format!("{:#x}", self.tree.root.value),
+            claimed: self.claimed.iter().filter(|&&claimed| claimed).count(),
+            unclaimed: self.claimed.iter().filter(|&&claimed| !claimed).count(),
+            unclaimed_total: unclaimed_totals.values().sum(),
+            unclaimed_totals,
+            unmatched_events: self.unmatched.clone(),
+        }
+    }
+
+    fn allocations_where(&self, claimed: bool) -> Vec<&Allocation> {
+        self.tree
+            .get_allocations()
+            .iter()
+            .zip(self.claimed.iter())
+            .filter(|(_, &is_claimed)| is_claimed == claimed)
+            .map(|(allocation, _)| allocation)
+            .collect()
+    }
+}
+
+fn event_key(event: &ClaimedEvent) -> Option<EventKey> {
+    let claimee = Felt::from_hex(&event.claimee).ok()?;
+    let timestamp = Felt::from_hex(&event.timestamp).ok()?;
+    Some((claimee, u64_to_felt(event.amount), timestamp))
+}
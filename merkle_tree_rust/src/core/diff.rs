@@ -0,0 +1,87 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use serde::Serialize;
+use starknet::core::types::Felt;
+use std::collections::{HashMap, HashSet};
+
+/// An allocation whose amount changed between two trees.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ModifiedAllocation {
+    pub before: Allocation,
+    pub after: Allocation,
+}
+
+/// Differences between a published tree and its successor.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TreeDiff {
+    pub old_root: String,
+    pub new_root: String,
+    pub added: Vec<Allocation>,
+    pub removed: Vec<Allocation>,
+    /// Allocations with the same address, timestamp and id but a different amount.
+    pub modified: Vec<ModifiedAllocation>,
+    /// Allocations present in both trees whose proof is no longer the same.
+    pub changed_proofs: usize,
+}
+
+/// Identifies an allocation across trees regardless of its amount.
+type AllocationKey = (Felt, Felt, Felt);
+
+impl MerkleTree {
+    /// Compares this tree with a newer one.
+    pub fn diff(&self, other: &MerkleTree) -> TreeDiff {
+        let old = unique_by_key(self.get_allocations());
+        let new = unique_by_key(other.get_allocations());
+        let old_by_key: HashMap<AllocationKey, &Allocation> = old.iter().copied().collect();
+        let new_by_key: HashMap<AllocationKey, &Allocation> = new.iter().copied().collect();
+
+        let mut added = vec![];
+        let mut modified = vec![];
+        let mut changed_proofs = 0;
+        for (key, allocation) in new.iter() {
+            match old_by_key.get(key) {
+                None => added.push((*allocation).clone()),
+                Some(previous) if previous.amount != allocation.amount => {
+                    modified.push(ModifiedAllocation {
+                        before: (*previous).clone(),
+                        after: (*allocation).clone(),
+                    });
+                    changed_proofs += 1;
+                }
+                Some(previous) => {
+                    if self.build_proof(previous).ok() != other.build_proof(allocation).ok() {
+                        changed_proofs += 1;
+                    }
+                }
+            }
+        }
+
+        let removed = old
+            .iter()
+            .filter(|(key, _)| !new_by_key.contains_key(key))
+            .map(|(_, allocation)| (*allocation).clone())
+            .collect();
+
+        TreeDiff {
+            old_root: format!("{:#x}", self.root.value),
+            new_root: format!("{:#x}", other.root.value),
+            added,
+            removed,
+            modified,
+            changed_proofs,
+        }
+    }
+}
+
+/// Keys allocations by `(address, timestamp, id)` in tree order, keeping the first of duplicates.
+fn unique_by_key(allocations: &[Allocation]) -> Vec<(AllocationKey, &Allocation)> {
+    let mut seen = HashSet::new();
+    allocations
+        .iter()
+        .filter_map(|allocation| {
+            let (address, _, timestamp, id) = allocation.to_felts().ok()?;
+            let key = (address, timestamp, id);
+            seen.insert(key).then_some((key, allocation))
+        })
+        .collect()
+}
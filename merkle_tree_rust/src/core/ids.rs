@@ -0,0 +1,116 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use starknet_crypto::poseidon_hash_many;
+use std::collections::{HashMap, HashSet};
+
+/// How `assign_ids` numbers allocations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IdMode {
+    /// 1, 2, 3... for each address, in input order.
+    #[default]
+    PerAddress,
+    /// 1, 2, 3... across all allocations, in input order.
+    Global,
+    /// Low 64 bits of `poseidon(address, amount, timestamp, occurrence)`, where `occurrence`
+    /// counts earlier identical allocations in the list from 0.
+    ContentHash,
+}
+
+/// Replaces the ids of allocations.
+///
+/// The `Claimer` marks an allocation as claimed by all its fields, so two leaves that only
+/// differ by id must not share it. With a `previous` tree, sequential ids continue after
+/// the ids it used, and any allocation that matches one of its leaves is rejected, since
+/// it would already be claimed. Sequential modes fail rather than pass `u64::MAX`.
+pub fn assign_ids(
+    allocations: Vec<Allocation>,
+    mode: IdMode,
+    previous: Option<&MerkleTree>,
+) -> Result<Vec<Allocation>, String> {
+    let published = match previous {
+        Some(tree) => tree.get_allocations().as_slice(),
+        None => &[],
+    };
+
+    // Next id to hand out, or None once u64::MAX has been used
+    let mut next_global = Some(1);
+    let mut next_per_address: HashMap<Felt, Option<u64>> = HashMap::new();
+    if mode != IdMode::ContentHash {
+        for allocation in published {
+            let (address, _, _, _) = allocation.to_felts()?;
+            let after = allocation.id.checked_add(1);
+            next_global = next_id_after(next_global, after);
+            let next = next_per_address.entry(address).or_insert(Some(1));
+            *next = next_id_after(*next, after);
+        }
+    }
+
+    let mut occurrences: HashMap<(Felt, Felt, Felt), u64> = HashMap::new();
+    let mut assigned = Vec::with_capacity(allocations.len());
+    for mut allocation in allocations {
+        let (address, amount, timestamp, _) = allocation.to_felts()?;
+        allocation.id = match mode {
+            IdMode::PerAddress => {
+                let next = next_per_address.entry(address).or_insert(Some(1));
+                take_id(next).ok_or_else(|| format!("Id overflow for address {:#x}", address))?
+            }
+            IdMode::Global => take_id(&mut next_global).ok_or("Id overflow")?,
+            IdMode::ContentHash => {
+                let occurrence = occurrences.entry((address, amount, timestamp)).or_insert(0);
+                let hash =
+                    poseidon_hash_many(&[address, amount, timestamp, Felt::from(*occurrence)])
+                        .to_bytes_be();
+                *occurrence += 1;
+                u64::from_be_bytes(hash[24..].try_into().unwrap())
+            }
+        };
+        assigned.push(allocation);
+    }
+
+    check_collisions(&assigned, published)?;
+    Ok(assigned)
+}
+
+/// Keeps the later of two next ids, where None is past u64::MAX.
+fn next_id_after(next: Option<u64>, after: Option<u64>) -> Option<u64> {
+    next.zip(after).map(|(next, after)| next.max(after))
+}
+
+/// Returns the next id and advances the counter, or None if ids are exhausted.
+fn take_id(next: &mut Option<u64>) -> Option<u64> {
+    let id = (*next)?;
+    *next = id.checked_add(1);
+    Some(id)
+}
+
+/// Checks that no two allocations, in the list or against published ones, are the same leaf.
+pub fn check_collisions(
+    allocations: &[Allocation],
+    published: &[Allocation],
+) -> Result<(), String> {
+    let published = published
+        .iter()
+        .map(|allocation| allocation.to_felts())
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    let mut seen = HashSet::new();
+    for allocation in allocations {
+        let felts = allocation.to_felts()?;
+        if published.contains(&felts) {
+            return Err(format!(
+                "Allocation for address {:#x} with id {} is already published",
+                felts.0, allocation.id
+            ));
+        }
+        if !seen.insert(felts) {
+            return Err(format!(
+                "Duplicate allocation for address {:#x} with id {}",
+                felts.0, allocation.id
+            ));
+        }
+    }
+    Ok(())
+}
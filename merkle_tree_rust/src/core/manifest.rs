@@ -0,0 +1,146 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::{LeafOrdering, MerkleTree};
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use starknet_crypto::poseidon_hash_many;
+
+/// Hash function of the leaves and nodes of a tree.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HashMode {
+    /// Pedersen leaves and sorted-pair Pedersen nodes, as checked by the `Claimer`.
+    #[default]
+    Pedersen,
+}
+
+/// How a level with an odd number of nodes is completed.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OddLeafPolicy {
+    /// The last leaf is duplicated and the last node of upper levels is paired with itself.
+    #[default]
+    DuplicateLast,
+}
+
+/// Record of how a root was built, enough to rebuild it from the same input.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BuildManifest {
+    /// Version of this crate that built the tree.
+    pub crate_version: String,
+    /// `input_digest` of the allocations, in leaf order.
+    pub input_digest: Felt,
+    pub hash_mode: HashMode,
+    pub ordering: LeafOrdering,
+    pub odd_leaf_policy: OddLeafPolicy,
+    pub leaf_count: usize,
+    pub total_amount: u128,
+    pub root: Felt,
+}
+
+/// Poseidon hash of `[count, address, amount, timestamp, id, ...]` over allocations.
+///
+/// Fields are hashed as felts, so the digest does not depend on how the JSON spelled them
+/// (hex case, leading zeros, key order or whitespace).
+pub fn input_digest(allocations: &[Allocation]) -> Result<Felt, String> {
+    let mut elements = vec![Felt::from(allocations.len())];
+    for allocation in allocations {
+        let (address, amount, timestamp, id) = allocation.to_felts()?;
+        elements.extend([address, amount, timestamp, id]);
+    }
+    Ok(poseidon_hash_many(&elements))
+}
+
+impl BuildManifest {
+    /// Describes a built tree.
+    ///
+    /// The digest covers the allocations in leaf order, so with a sorting `LeafOrdering` any
+    /// permutation of the input has the same manifest.
+    pub fn for_tree(tree: &MerkleTree) -> Result<Self, String> {
+        let allocations = tree.get_allocations();
+        Ok(BuildManifest {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            input_digest: input_digest(allocations)?,
+            hash_mode: HashMode::Pedersen,
+            ordering: tree.ordering(),
+            odd_leaf_policy: OddLeafPolicy::DuplicateLast,
+            leaf_count: allocations.len(),
+            total_amount: allocations.iter().map(|a| a.amount as u128).sum(),
+            root: tree.root.value,
+        })
+    }
+
+    /// Builds a tree and its manifest.
+    pub fn build(
+        allocations: Vec<Allocation>,
+        ordering: LeafOrdering,
+    ) -> Result<(MerkleTree, Self), String> {
+        check_allocations(&allocations)?;
+        let tree = MerkleTree::with_ordering(allocations, ordering);
+        let manifest = BuildManifest::for_tree(&tree)?;
+        Ok((tree, manifest))
+    }
+
+    /// Rebuilds a tree from its input and checks that it matches this manifest.
+    ///
+    /// Every field but `crate_version` must match; a different version that gives the same
+    /// tree still reproduces it.
+    pub fn reproduce(&self, allocations: Vec<Allocation>) -> Result<MerkleTree, String> {
+        let (tree, rebuilt) = BuildManifest::build(allocations, self.ordering)?;
+
+        let mut mismatches = vec![];
+        if rebuilt.input_digest != self.input_digest {
+            mismatches.push(format!(
+                "input_digest: expected {:#x}, got {:#x}",
+                self.input_digest, rebuilt.input_digest
+            ));
+        }
+        if rebuilt.hash_mode != self.hash_mode {
+            mismatches.push(format!(
+                "hash_mode: expected {:?}, got {:?}",
+                self.hash_mode, rebuilt.hash_mode
+            ));
+        }
+        if rebuilt.odd_leaf_policy != self.odd_leaf_policy {
+            mismatches.push(format!(
+                "odd_leaf_policy: expected {:?}, got {:?}",
+                self.odd_leaf_policy, rebuilt.odd_leaf_policy
+            ));
+        }
+        if rebuilt.leaf_count != self.leaf_count {
+            mismatches.push(format!(
+                "leaf_count: expected {}, got {}",
+                self.leaf_count, rebuilt.leaf_count
+            ));
+        }
+        if rebuilt.total_amount != self.total_amount {
+            mismatches.push(format!(
+                "total_amount: expected {}, got {}",
+                self.total_amount, rebuilt.total_amount
+            ));
+        }
+        if rebuilt.root != self.root {
+            mismatches.push(format!(
+                "root: expected {:#x}, got {:#x}",
+                self.root, rebuilt.root
+            ));
+        }
+
+        if !mismatches.is_empty() {
+            return Err(format!(
+                "Build does not match manifest:\n{}",
+                mismatches.join("\n")
+            ));
+        }
+        Ok(tree)
+    }
+}
+
+fn check_allocations(allocations: &[Allocation]) -> Result<(), String> {
+    if allocations.is_empty() {
+        return Err("Tree has no allocations".to_string());
+    }
+    for allocation in allocations {
+        allocation.to_felts()?;
+    }
+    Ok(())
+}
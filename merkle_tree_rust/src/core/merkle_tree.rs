@@ -1,15 +1,63 @@
 use crate::core::allocation::Allocation;
 use crate::core::node::Node;
-use starknet::core::types::Felt;
+use crate::core::proof::encode_calldata;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use starknet::core::types::{Call, Felt};
+use starknet::core::utils::get_selector_from_name;
+use std::collections::HashMap;
+
+/// `IClaimer` entry point that checks a proof against the stored root.
+pub const CLAIM_ENTRYPOINT: &str = "claim";
+
+/// Order in which allocations are laid out as leaves, which determines the root.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LeafOrdering {
+    /// Leaves follow the input order.
+    #[default]
+    Insertion,
+    /// Leaves are sorted by hash, so the root only depends on the set of allocations.
+    LeafHash,
+    /// Leaves are sorted by address, timestamp, id and then amount, compared as felts.
+    AddressTimestampId,
+}
+
+impl LeafOrdering {
+    /// Reorders allocations in place; sorting is stable so equal keys keep their input order.
+    pub fn sort(&self, allocations: &mut [Allocation]) {
+        match self {
+            LeafOrdering::Insertion => {}
+            LeafOrdering::LeafHash => {
+                allocations.sort_by_cached_key(|allocation| allocation.leaf_hash().ok())
+            }
+            LeafOrdering::AddressTimestampId => allocations.sort_by_cached_key(|allocation| {
+                allocation
+                    .to_felts()
+                    .ok()
+                    .map(|(address, amount, timestamp, id)| (address, timestamp, id, amount))
+            }),
+        }
+    }
+}
 
 pub struct MerkleTree {
     pub root: Node,
     allocations: Vec<Allocation>,
+    address_index: HashMap<Felt, Vec<usize>>,
+    ordering: LeafOrdering,
 }
 
 impl MerkleTree {
     /// Creates a new Merkle tree from a list of allocations.
     pub fn new(allocations: Vec<Allocation>) -> Self {
+        MerkleTree::with_ordering(allocations, LeafOrdering::Insertion)
+    }
+
+    /// Creates a Merkle tree whose leaves are laid out in the given order.
+    pub fn with_ordering(mut allocations: Vec<Allocation>, ordering: LeafOrdering) -> Self {
+        ordering.sort(&mut allocations);
+
         let mut leaves: Vec<Node> = allocations
             .clone()
             .into_iter()
@@ -21,15 +69,48 @@ impl MerkleTree {
         }
 
         let root = build_tree(leaves);
+        let address_index = build_address_index(&allocations);
 
-        MerkleTree { root, allocations }
+        MerkleTree {
+            root,
+            allocations,
+            address_index,
+            ordering,
+        }
+    }
+
+    /// Returns how the leaves of the tree are ordered.
+    pub fn ordering(&self) -> LeafOrdering {
+        self.ordering
     }
 
-    /// Returns the list of allocations in the Merkle tree.
+    /// Returns the list of allocations in the Merkle tree, in leaf order.
     pub fn get_allocations(&self) -> &Vec<Allocation> {
         &self.allocations
     }
 
+    /// Returns every allocation held by an address, in leaf order.
+    pub fn allocations_for(&self, address: &str) -> Vec<&Allocation> {
+        let Ok(address) = Felt::from_hex(address) else {
+            return vec![];
+        };
+
+        self.address_index
+            .get(&address)
+            .map(|indexes| indexes.iter().map(|&i| &self.allocations[i]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Generates calldata for every allocation held by an address.
+    pub fn proofs_for(&self, address: &str) -> Result<Vec<Vec<String>>, String> {
+        Felt::from_hex(address).map_err(|_| "Invalid address")?;
+
+        self.allocations_for(address)
+            .into_iter()
+            .map(|allocation| self.allocation_calldata(allocation))
+            .collect()
+    }
+
     /// Generates calldata for a specific allocation.
     pub fn build_address_calldata(
         &self,
@@ -45,42 +126,136 @@ impl MerkleTree {
             id,
         };
 
-        // Traverse the tree to find the proof path
-        let mut hashes: Vec<Felt> = vec![];
-        let mut current_node = &self.root;
+        self.allocation_calldata(&allocation)
+    }
 
-        loop {
-            if current_node.left_child.is_none() && current_node.right_child.is_none() {
-                break;
-            }
+    /// Builds a `Claimer::claim(amount, timestamp, id, proof)` call for the allocation's owner.
+    ///
+    /// The contract reads the claimee from the caller, so the address only locates the leaf.
+    pub fn build_claim_call(
+        &self,
+        contract_address: &str,
+        address: &str,
+        amount: u64,
+        timestamp: &str,
+        id: u64,
+    ) -> Result<Call, String> {
+        let to = Felt::from_hex(contract_address).map_err(|_| "Invalid contract address")?;
+        let allocation = Allocation {
+            address: address.to_string(),
+            amount,
+            timestamp: timestamp.to_string(),
+            id,
+        };
 
-            let left = current_node.left_child.as_ref().unwrap();
-            let right = current_node.right_child.as_ref().unwrap();
+        let (_, amount, timestamp, id) = allocation.to_felts()?;
+        if timestamp.bits() > 128 {
+            return Err("Timestamp does not fit in u128".to_string());
+        }
+        let proof = self.build_proof(&allocation)?;
+
+        Ok(Call {
+            to,
+            selector: get_selector_from_name(CLAIM_ENTRYPOINT).unwrap(),
+            calldata: encode_calldata(&[amount, timestamp, id], proof),
+        })
+    }
 
-            if left.accessible_allocations.contains(&allocation) {
-                hashes.push(right.value);
+    /// Merges the current tree with new allocations, keeping its leaf ordering.
+    pub fn merge_merkle_trees(&self, new_allocations: Vec<Allocation>) -> MerkleTree {
+        let mut combined_allocations = self.get_allocations().clone();
+        combined_allocations.extend(new_allocations.clone());
+        MerkleTree::with_ordering(combined_allocations, self.ordering)
+    }
+
+    /// Builds `[address, amount, timestamp, id, ...proof]` calldata for an allocation.
+    fn allocation_calldata(&self, allocation: &Allocation) -> Result<Vec<String>, String> {
+        let hashes = self.build_proof(allocation)?;
+        let felts = allocation.to_felts()?;
+        let mut calldata = vec![felts.0, felts.1, felts.2, felts.3];
+        calldata.extend(hashes);
+
+        Ok(calldata.iter().map(|f| format!("{:#x}", f)).collect())
+    }
+
+    /// Collects the sibling hashes from an allocation's leaf up to the root.
+    pub(crate) fn build_proof(&self, allocation: &Allocation) -> Result<Vec<Felt>, String> {
+        let mut hashes: Vec<Felt> = self
+            .leaf_path(allocation)?
+            .iter()
+            .map(|(_, sibling)| sibling.value)
+            .collect();
+        hashes.reverse();
+        Ok(hashes)
+    }
+
+    /// Walks from the root to an allocation's leaf, returning for each level whether the path
+    /// goes to the `max` child, and the sibling it leaves behind.
+    pub(crate) fn leaf_path(&self, allocation: &Allocation) -> Result<Vec<(bool, &Node)>, String> {
+        let mut path = vec![];
+        let mut current_node = &self.root;
+
+        while let (Some(left), Some(right)) = (&current_node.left_child, &current_node.right_child)
+        {
+            if left.accessible_allocations.contains(allocation) {
+                path.push((false, right.as_ref()));
                 current_node = left;
-            } else if right.accessible_allocations.contains(&allocation) {
-                hashes.push(left.value);
+            } else if right.accessible_allocations.contains(allocation) {
+                path.push((true, left.as_ref()));
                 current_node = right;
             } else {
                 return Err("Allocation not found".to_string());
             }
         }
 
-        hashes.reverse();
-        let felts = allocation.to_felts().unwrap();
-        let mut calldata = vec![felts.0, felts.1, felts.2, felts.3];
-        calldata.extend(hashes);
+        if !current_node.accessible_allocations.contains(allocation) {
+            return Err("Allocation not found".to_string());
+        }
+        Ok(path)
+    }
+}
 
-        Ok(calldata.iter().map(|f| format!("{:#x}", f)).collect())
+/// On-disk form of a tree: the allocations it was built from and the root they produce.
+#[derive(Serialize, Deserialize)]
+struct SerializedMerkleTree {
+    root: String,
+    /// Dumps written before orderings existed were built in insertion order.
+    #[serde(default)]
+    ordering: LeafOrdering,
+    allocations: Vec<Allocation>,
+}
+
+impl Serialize for MerkleTree {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedMerkleTree {
+            root: format!("{:#x}", self.root.value),
+            ordering: self.ordering,
+            allocations: self.allocations.clone(),
+        }
+        .serialize(serializer)
     }
+}
 
-    /// Merges the current tree with new allocations.
-    pub fn merge_merkle_trees(&self, new_allocations: Vec<Allocation>) -> MerkleTree {
-        let mut combined_allocations = self.get_allocations().clone();
-        combined_allocations.extend(new_allocations.clone());
-        MerkleTree::new(combined_allocations)
+impl<'de> Deserialize<'de> for MerkleTree {
+    /// Rebuilds the tree and rejects dumps whose root does not match their allocations.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedMerkleTree::deserialize(deserializer)?;
+        let root =
+            Felt::from_hex(&serialized.root).map_err(|_| D::Error::custom("Invalid root"))?;
+
+        if serialized.allocations.is_empty() {
+            return Err(D::Error::custom("Tree has no allocations"));
+        }
+        for allocation in serialized.allocations.iter() {
+            allocation.to_felts().map_err(D::Error::custom)?;
+        }
+
+        let tree = MerkleTree::with_ordering(serialized.allocations, serialized.ordering);
+        if tree.root.value != root {
+            return Err(D::Error::custom("Root does not match allocations"));
+        }
+
+        Ok(tree)
     }
 }
 
@@ -101,3 +276,14 @@ fn build_tree(mut leaves: Vec<Node>) -> Node {
     }
     leaves.remove(0)
 }
+
+/// Maps each address to the positions of its allocations.
+fn build_address_index(allocations: &[Allocation]) -> HashMap<Felt, Vec<usize>> {
+    let mut index: HashMap<Felt, Vec<usize>> = HashMap::new();
+    for (i, allocation) in allocations.iter().enumerate() {
+        if let Ok((address, _, _, _)) = allocation.to_felts() {
+            index.entry(address).or_default().push(i);
+        }
+    }
+    index
+}
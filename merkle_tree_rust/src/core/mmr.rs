@@ -0,0 +1,248 @@
+use crate::core::allocation::Allocation;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use starknet_crypto::{pedersen_hash, poseidon_hash};
+use std::collections::HashMap;
+
+/// Hash function of the nodes and peak bagging of a `MerkleMountainRange`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MmrHasher {
+    #[default]
+    Pedersen,
+    /// Two-element Poseidon, `hades_permutation(a, b, 2).0` in Cairo.
+    Poseidon,
+}
+
+impl MmrHasher {
+    /// Hashes two felts in the given order.
+    pub fn hash(&self, a: &Felt, b: &Felt) -> Felt {
+        match self {
+            MmrHasher::Pedersen => pedersen_hash(a, b),
+            MmrHasher::Poseidon => poseidon_hash(*a, *b),
+        }
+    }
+
+    /// Hashes two nodes in sorted order, so proofs need no left/right flags.
+    pub fn hash_pair(&self, a: &Felt, b: &Felt) -> Felt {
+        if a < b {
+            self.hash(a, b)
+        } else {
+            self.hash(b, a)
+        }
+    }
+}
+
+/// Proof of a leaf against the root of a range of `leaf_count` leaves.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MmrProof {
+    pub leaf_index: usize,
+    pub leaf_count: usize,
+    /// Siblings from the leaf up to the peak of its mountain.
+    pub siblings: Vec<Felt>,
+    /// Every peak, highest mountain first.
+    pub peaks: Vec<Felt>,
+}
+
+/// Append-only Merkle Mountain Range over allocations.
+///
+/// Leaves are `Claimer` leaf hashes. Each mountain is a perfect tree hashed in sorted pairs, and
+/// the root bags the peaks from right to left, then commits to the leaf count:
+/// `hash(leaf_count, hash(peak_0, hash(peak_1, ... peak_n)))`. Appending never changes an
+/// existing node, so an older proof stays a prefix of the new one.
+pub struct MerkleMountainRange {
+    hasher: MmrHasher,
+    allocations: Vec<Allocation>,
+    /// `levels[k][i]` is the node of height `k` above leaves `i * 2^k .. (i + 1) * 2^k`.
+    levels: Vec<Vec<Felt>>,
+    leaf_index: HashMap<Allocation, usize>,
+}
+
+impl MerkleMountainRange {
+    /// Creates an empty range hashed with `hasher`.
+    pub fn new(hasher: MmrHasher) -> Self {
+        MerkleMountainRange {
+            hasher,
+            allocations: vec![],
+            levels: vec![vec![]],
+            leaf_index: HashMap::new(),
+        }
+    }
+
+    /// Appends allocations one by one, in the given order.
+    pub fn from_allocations(
+        allocations: Vec<Allocation>,
+        hasher: MmrHasher,
+    ) -> Result<Self, String> {
+        let mut mmr = MerkleMountainRange::new(hasher);
+        for allocation in allocations {
+            mmr.append(allocation)?;
+        }
+        Ok(mmr)
+    }
+
+    /// Appends an allocation, merging equal-height mountains, and returns its leaf index.
+    pub fn append(&mut self, allocation: Allocation) -> Result<usize, String> {
+        let leaf = allocation.leaf_hash()?;
+        let index = self.allocations.len();
+        self.leaf_index.entry(allocation.clone()).or_insert(index);
+        self.allocations.push(allocation);
+        self.levels[0].push(leaf);
+
+        // Every new node that is a right child completes a pair
+        let (mut height, mut position) = (0, index);
+        while position % 2 == 1 {
+            let level = &self.levels[height];
+            let parent = self
+                .hasher
+                .hash_pair(&level[position - 1], &level[position]);
+            if self.levels.len() == height + 1 {
+                self.levels.push(vec![]);
+            }
+            self.levels[height + 1].push(parent);
+            height += 1;
+            position /= 2;
+        }
+        Ok(index)
+    }
+
+    /// Returns the hash function of the range.
+    pub fn hasher(&self) -> MmrHasher {
+        self.hasher
+    }
+
+    /// Returns the number of appended allocations.
+    pub fn leaf_count(&self) -> usize {
+        self.allocations.len()
+    }
+
+    /// Returns the allocations in leaf order.
+    pub fn get_allocations(&self) -> &Vec<Allocation> {
+        &self.allocations
+    }
+
+    /// Returns the peaks, highest mountain first.
+    pub fn peaks(&self) -> Vec<Felt> {
+        self.levels
+            .iter()
+            .rev()
+            .filter(|level| level.len() % 2 == 1)
+            .map(|level| *level.last().unwrap())
+            .collect()
+    }
+
+    /// Returns the bagged peaks committed to the leaf count, as `verify_mmr_proof` expects.
+    pub fn root(&self) -> Result<Felt, String> {
+        if self.allocations.is_empty() {
+            return Err("Tree has no allocations".to_string());
+        }
+        Ok(bag_peaks(self.hasher, self.leaf_count(), &self.peaks()))
+    }
+
+    /// Returns the proof of the leaf at `leaf_index` against the current root.
+    pub fn proof(&self, leaf_index: usize) -> Result<MmrProof, String> {
+        if leaf_index >= self.leaf_count() {
+            return Err("Leaf index out of range".to_string());
+        }
+        Ok(MmrProof {
+            leaf_index,
+            leaf_count: self.leaf_count(),
+            siblings: self.siblings(leaf_index, 0, vec![]),
+            peaks: self.peaks(),
+        })
+    }
+
+    /// Returns the proof of the first leaf holding an allocation.
+    pub fn proof_for(&self, allocation: &Allocation) -> Result<MmrProof, String> {
+        let index = self
+            .leaf_index
+            .get(allocation)
+            .ok_or("Allocation not found")?;
+        self.proof(*index)
+    }
+
+    /// Brings a proof issued before later appends up to date in O(log n).
+    ///
+    /// The old siblings are kept and only the ones above the old peak are added.
+    pub fn update_proof(&self, proof: &MmrProof) -> Result<MmrProof, String> {
+        if proof.leaf_count > self.leaf_count() || proof.leaf_index >= proof.leaf_count {
+            return Err("Proof is not from this range".to_string());
+        }
+        let height = proof.siblings.len();
+        let mut index = proof.leaf_index;
+        for (level, sibling) in self.levels.iter().zip(proof.siblings.iter()) {
+            if level.get(index ^ 1) != Some(sibling) {
+                return Err("Proof is not from this range".to_string());
+            }
+            index /= 2;
+        }
+
+        Ok(MmrProof {
+            leaf_index: proof.leaf_index,
+            leaf_count: self.leaf_count(),
+            siblings: self.siblings(proof.leaf_index, height, proof.siblings.clone()),
+            peaks: self.peaks(),
+        })
+    }
+
+    /// Extends `siblings`, already known up to `height`, up to the peak of the leaf's mountain.
+    fn siblings(&self, leaf_index: usize, height: usize, mut siblings: Vec<Felt>) -> Vec<Felt> {
+        let mut index = leaf_index >> height;
+        for level in self.levels[height..].iter() {
+            // A node without a sibling yet is a peak
+            match level.get(index ^ 1) {
+                Some(sibling) => siblings.push(*sibling),
+                None => break,
+            }
+            index /= 2;
+        }
+        siblings
+    }
+}
+
+/// Bags peaks from right to left and commits to the leaf count.
+pub fn bag_peaks(hasher: MmrHasher, leaf_count: usize, peaks: &[Felt]) -> Felt {
+    let bagged = peaks
+        .iter()
+        .rev()
+        .copied()
+        .reduce(|acc, peak| hasher.hash(&peak, &acc))
+        .unwrap_or(Felt::ZERO);
+    hasher.hash(&Felt::from(leaf_count), &bagged)
+}
+
+/// Checks a leaf against a bagged root.
+///
+/// The mountain of the leaf and its height follow from `leaf_index` and `leaf_count`, so the
+/// proof must have exactly that many siblings and one peak per mountain.
+pub fn verify_mmr_proof(hasher: MmrHasher, root: Felt, leaf: Felt, proof: &MmrProof) -> bool {
+    if proof.leaf_index >= proof.leaf_count
+        || proof.peaks.len() != proof.leaf_count.count_ones() as usize
+    {
+        return false;
+    }
+
+    // Mountains are the powers of two of the leaf count, highest first
+    let mut start = 0;
+    let mut mountain = 0;
+    for height in (0..usize::BITS).rev() {
+        let size = 1usize << height;
+        if proof.leaf_count & size == 0 {
+            continue;
+        }
+        if proof.leaf_index < start + size {
+            if proof.siblings.len() != height as usize {
+                return false;
+            }
+            break;
+        }
+        start += size;
+        mountain += 1;
+    }
+
+    let peak = proof
+        .siblings
+        .iter()
+        .fold(leaf, |current, sibling| hasher.hash_pair(&current, sibling));
+    proof.peaks[mountain] == peak && bag_peaks(hasher, proof.leaf_count, &proof.peaks) == root
+}
@@ -1,4 +1,4 @@
-use crate::core::allocation::{Allocation};
+use crate::core::allocation::Allocation;
 use starknet::core::types::Felt;
 use starknet_crypto::pedersen_hash;
 use std::collections::HashSet;
@@ -30,11 +30,7 @@ impl Node {
 
     /// Creates a new leaf node from an allocation.
     pub fn new_leaf(allocation: Allocation) -> Self {
-        let (address, amount, timestamp, id) = allocation.to_felts().unwrap();
-
-        let intermediate_hash = pedersen_hash(&address, &amount);
-        let intermediate_hash = pedersen_hash(&intermediate_hash, &timestamp);
-        let value = pedersen_hash(&intermediate_hash, &id);
+        let value = allocation.leaf_hash().unwrap();
 
         Node {
             left_child: None,
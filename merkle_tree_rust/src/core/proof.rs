@@ -0,0 +1,102 @@
+use starknet::core::types::Felt;
+use starknet_crypto::pedersen_hash;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Hashes two nodes in sorted order, as `Node::new` and the Cairo verifier do.
+pub fn hash_pair(a: &Felt, b: &Felt) -> Felt {
+    if a < b {
+        pedersen_hash(a, b)
+    } else {
+        pedersen_hash(b, a)
+    }
+}
+
+/// Folds a proof into the root it commits to, starting from a leaf hash.
+pub fn compute_root(leaf: Felt, proof: &[Felt]) -> Felt {
+    proof
+        .iter()
+        .fold(leaf, |current, sibling| hash_pair(&current, sibling))
+}
+
+/// Checks that a leaf and its proof resolve to the expected root.
+pub fn verify_proof(root: Felt, leaf: Felt, proof: &[Felt]) -> bool {
+    compute_root(leaf, proof) == root
+}
+
+/// Parses a list of hex strings, such as the tail of `build_address_calldata`.
+pub fn parse_proof(proof: &[String]) -> Result<Vec<Felt>, String> {
+    proof
+        .iter()
+        .map(|hash| Felt::from_hex(hash).map_err(|_| format!("Invalid proof hash: {}", hash)))
+        .collect()
+}
+
+/// Hashes nodes level by level up to a single root, pairing an odd last node with itself.
+///
+/// The first level is returned as given; `build_leaf_levels` also duplicates a lone leaf as
+/// `MerkleTree::new` does.
+pub fn build_levels(nodes: Vec<Felt>) -> Vec<Vec<Felt>> {
+    build_levels_with(nodes, hash_pair)
+}
+
+/// Like `build_levels`, with any node type and pair hash.
+pub fn build_levels_with<T: Copy>(nodes: Vec<T>, hash: impl Fn(&T, &T) -> T) -> Vec<Vec<T>> {
+    let mut levels = vec![nodes];
+    while levels.last().unwrap().len() > 1 {
+        let next_level = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| hash(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+            .collect();
+        levels.push(next_level);
+    }
+    levels
+}
+
+/// Duplicates the last of an odd number of leaves, as `MerkleTree::new` does, and builds the
+/// levels above them.
+pub fn build_leaf_levels(leaves: Vec<Felt>) -> Vec<Vec<Felt>> {
+    build_leaf_levels_with(leaves, hash_pair)
+}
+
+/// Like `build_leaf_levels`, with any node type and pair hash.
+pub fn build_leaf_levels_with<T: Copy>(
+    mut leaves: Vec<T>,
+    hash: impl Fn(&T, &T) -> T,
+) -> Vec<Vec<T>> {
+    if leaves.len() % 2 == 1 {
+        leaves.push(*leaves.last().unwrap());
+    }
+    build_levels_with(leaves, hash)
+}
+
+/// Collects the sibling hashes of the node at `index` of the first level of `build_levels`.
+pub fn proof_from_levels<T: Copy>(levels: &[Vec<T>], mut index: usize) -> Vec<T> {
+    let mut proof = vec![];
+    for level in levels[..levels.len() - 1].iter() {
+        proof.push(*level.get(index ^ 1).unwrap_or(&level[index]));
+        index /= 2;
+    }
+    proof
+}
+
+/// Maps each item to the first position it appears at, the leaf its proofs are built for.
+pub fn leaf_positions<T: Clone + Eq + Hash>(items: &[T]) -> HashMap<T, usize> {
+    let mut positions = HashMap::new();
+    for (i, item) in items.iter().enumerate() {
+        positions.entry(item.clone()).or_insert(i);
+    }
+    positions
+}
+
+/// Appends a proof to call arguments as `[fields..., len, proof...]`.
+///
+/// Cairo serializes `Array<felt252>` as its length followed by its elements.
+pub fn encode_calldata(fields: &[Felt], proof: Vec<Felt>) -> Vec<Felt> {
+    let mut calldata = fields.to_vec();
+    calldata.push(Felt::from(proof.len()));
+    calldata.extend(proof);
+    calldata
+}
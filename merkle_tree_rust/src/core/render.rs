@@ -0,0 +1,208 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use crate::core::node::Node;
+use starknet::core::types::Felt;
+use std::fmt::Write;
+
+/// Role of a node with respect to a highlighted proof.
+#[derive(Clone, Copy)]
+enum Mark {
+    None,
+    /// On the path from the root to the highlighted leaf.
+    Path,
+    /// Sibling of the path, at this position of the proof.
+    Proof(usize),
+}
+
+impl MerkleTree {
+    /// Renders the tree as a Graphviz `digraph`.
+    ///
+    /// Children are labelled `min` and `max`, the order in which they were hashed.
+    pub fn to_dot(&self) -> String {
+        self.render_dot(None)
+    }
+
+    /// Renders the tree as a Graphviz `digraph`, filling the path of an allocation and its proof.
+    pub fn to_dot_highlighting(&self, allocation: &Allocation) -> Result<String, String> {
+        Ok(self.render_dot(Some(&self.proof_path(allocation)?)))
+    }
+
+    /// Renders the tree as indented text, one node per line.
+    pub fn to_ascii(&self) -> String {
+        self.render_ascii(None)
+    }
+
+    /// Renders the tree as indented text, marking the path of an allocation and its proof.
+    pub fn to_ascii_highlighting(&self, allocation: &Allocation) -> Result<String, String> {
+        Ok(self.render_ascii(Some(&self.proof_path(allocation)?)))
+    }
+
+    /// Returns the branches taken from the root to an allocation's leaf, `true` going to `max`.
+    ///
+    /// Comes from the same walk as `build_proof`, so marked siblings are the proof it returns.
+    fn proof_path(&self, allocation: &Allocation) -> Result<Vec<bool>, String> {
+        Ok(self
+            .leaf_path(allocation)?
+            .iter()
+            .map(|(to_max, _)| *to_max)
+            .collect())
+    }
+
+    fn render_dot(&self, path: Option<&[bool]>) -> String {
+        let mut dot = String::from("digraph merkle_tree {\n");
+        dot.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+
+        let mut next_id = 0;
+        write_dot_node(&mut dot, &self.root, root_mark(path), path, &mut next_id);
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn render_ascii(&self, path: Option<&[bool]>) -> String {
+        let mut ascii = format!("root {}\n", describe(&self.root, root_mark(path)));
+        write_ascii_children(&mut ascii, &self.root, path, "");
+        ascii
+    }
+}
+
+/// Writes a node and its subtree, returning the id of the node.
+fn write_dot_node(
+    dot: &mut String,
+    node: &Node,
+    mark: Mark,
+    path: Option<&[bool]>,
+    next_id: &mut usize,
+) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+
+    let mut label = short_hash(&node.value);
+    if let Some(allocation) = leaf_allocation(node) {
+        label.push_str("\\n");
+        label.push_str(&summary(allocation));
+    }
+    let style = match mark {
+        Mark::None => String::new(),
+        Mark::Path => ", style=filled, fillcolor=\"lightblue\"".to_string(),
+        Mark::Proof(index) => {
+            label.push_str(&format!("\\nproof[{}]", index));
+            ", style=filled, fillcolor=\"orange\"".to_string()
+        }
+    };
+    writeln!(dot, "    n{} [label=\"{}\"{}];", id, label, style).unwrap();
+
+    for (child, child_mark, child_path, side) in children(node, path) {
+        let child_id = write_dot_node(dot, child, child_mark, child_path, next_id);
+        writeln!(dot, "    n{} -> n{} [label=\"{}\"];", id, child_id, side).unwrap();
+    }
+    id
+}
+
+fn write_ascii_children(ascii: &mut String, node: &Node, path: Option<&[bool]>, prefix: &str) {
+    let children = children(node, path);
+    let count = children.len();
+
+    for (i, (child, child_mark, child_path, side)) in children.into_iter().enumerate() {
+        let (branch, indent) = if i + 1 == count {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        writeln!(
+            ascii,
+            "{}{}{} {}",
+            prefix,
+            branch,
+            side,
+            describe(child, child_mark)
+        )
+        .unwrap();
+        write_ascii_children(ascii, child, child_path, &format!("{}{}", prefix, indent));
+    }
+}
+
+/// Lists the children of a node with their marks and, for the one on the path, the rest of it.
+///
+/// `path` is only set for nodes on the highlighted path.
+fn children<'a, 'p>(
+    node: &'a Node,
+    path: Option<&'p [bool]>,
+) -> Vec<(&'a Node, Mark, Option<&'p [bool]>, &'static str)> {
+    let (Some(left), Some(right)) = (&node.left_child, &node.right_child) else {
+        return vec![];
+    };
+
+    let marks = match path {
+        Some(path) if !path.is_empty() => {
+            // The sibling at depth d from the root is element `len - d` of the proof
+            let sibling = Mark::Proof(path.len() - 1);
+            let rest = Some(&path[1..]);
+            if path[0] {
+                [(sibling, None), (Mark::Path, rest)]
+            } else {
+                [(Mark::Path, rest), (sibling, None)]
+            }
+        }
+        _ => [(Mark::None, None), (Mark::None, None)],
+    };
+
+    vec![
+        (left.as_ref(), marks[0].0, marks[0].1, "min"),
+        (right.as_ref(), marks[1].0, marks[1].1, "max"),
+    ]
+}
+
+/// One-line description of a node: its hash, its allocation for leaves and its mark.
+fn describe(node: &Node, mark: Mark) -> String {
+    let mut description = match leaf_allocation(node) {
+        Some(allocation) => format!("leaf {} [{}]", short_hash(&node.value), summary(allocation)),
+        None => short_hash(&node.value),
+    };
+
+    if let (Some(left), Some(right)) = (&node.left_child, &node.right_child) {
+        if left.value == right.value {
+            description.push_str(" (self-paired)");
+        }
+    }
+    match mark {
+        Mark::None => {}
+        Mark::Path => description.push_str(" <- path"),
+        Mark::Proof(index) => description.push_str(&format!(" <- proof[{}]", index)),
+    }
+    description
+}
+
+fn root_mark(path: Option<&[bool]>) -> Mark {
+    match path {
+        Some(_) => Mark::Path,
+        None => Mark::None,
+    }
+}
+
+fn leaf_allocation(node: &Node) -> Option<&Allocation> {
+    if node.left_child.is_some() || node.right_child.is_some() {
+        return None;
+    }
+    node.accessible_allocations.iter().next()
+}
+
+fn summary(allocation: &Allocation) -> String {
+    let address = match Felt::from_hex(&allocation.address) {
+        Ok(address) => short_hash(&address),
+        Err(_) => allocation.address.clone(),
+    };
+    format!(
+        "{} amount={} timestamp={} id={}",
+        address, allocation.amount, allocation.timestamp, allocation.id
+    )
+}
+
+/// Keeps the first six and last four hex digits of a hash.
+fn short_hash(hash: &Felt) -> String {
+    let hex = format!("{:x}", hash);
+    if hex.len() <= 12 {
+        return format!("0x{}", hex);
+    }
+    format!("0x{}…{}", &hex[..6], &hex[hex.len() - 4..])
+}
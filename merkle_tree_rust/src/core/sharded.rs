@@ -0,0 +1,294 @@
+use crate::core::allocation::Allocation;
+use crate::core::proof::{build_levels, proof_from_levels};
+use crate::core::stream::{read_json_lines, SpilledLevels, StreamingTreeBuilder};
+use starknet::core::types::Felt;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// How allocations are partitioned into shards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShardStrategy {
+    /// Groups allocations by the top `bits` bits of their 252-bit address, up to `2^bits` shards.
+    AddressPrefix { bits: u32 },
+    /// Splits allocations, in input order, into consecutive ranges of `size` leaves.
+    IndexRange { size: usize },
+}
+
+/// One subtree of a sharded tree, built like a standalone `MerkleTree`.
+///
+/// Only the root is kept in memory; with `ShardedMerkleTree::with_spill` the levels and the
+/// allocations of the shard are written to its own directory.
+pub struct Shard {
+    /// Address prefix or range number the shard was built for.
+    pub key: usize,
+    /// Number of allocations in the shard.
+    pub leaf_count: usize,
+    root: Felt,
+    dir: Option<PathBuf>,
+}
+
+impl Shard {
+    /// Streams the allocations of a shard into a subtree and drops them.
+    fn build(key: usize, allocations: Vec<Allocation>, dir: Option<&Path>) -> Result<Self, String> {
+        let dir = dir.map(|dir| dir.join(format!("shard_{}", key)));
+        let mut builder = match &dir {
+            Some(dir) => StreamingTreeBuilder::with_spill(dir)?,
+            None => StreamingTreeBuilder::new(),
+        };
+        for allocation in allocations.iter() {
+            builder.push(allocation)?;
+        }
+        let root = builder.finalize()?;
+
+        if let Some(dir) = &dir {
+            write_allocations(&allocations_path(dir), &allocations)?;
+        }
+
+        Ok(Shard {
+            key,
+            leaf_count: allocations.len(),
+            root,
+            dir,
+        })
+    }
+
+    /// Returns the root of the subtree, which is a leaf of the top tree.
+    pub fn root(&self) -> Felt {
+        self.root
+    }
+
+    /// Reads the allocations of the shard back from disk, in leaf order.
+    pub fn get_allocations(&self) -> Result<Vec<Allocation>, String> {
+        self.read_allocations()?.collect()
+    }
+
+    fn read_allocations(&self) -> Result<impl Iterator<Item = Result<Allocation, String>>, String> {
+        let path = allocations_path(self.spill_dir()?);
+        let file = File::open(&path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+        Ok(read_json_lines(BufReader::new(file)))
+    }
+
+    fn spill_dir(&self) -> Result<&Path, String> {
+        self.dir
+            .as_deref()
+            .ok_or_else(|| "Shards were not spilled to disk".to_string())
+    }
+}
+
+/// Merkle tree split into independently built shards under a root-of-roots.
+///
+/// Leaves and nodes are hashed as in `MerkleTree`, so a proof is the shard proof followed by the
+/// top proof and checks against the root with `compute_root`, like any `Claimer` proof. Shards are
+/// built one at a time and only their roots are kept, so memory beyond the input grows with the
+/// number of shards rather than the number of allocations.
+pub struct ShardedMerkleTree {
+    strategy: ShardStrategy,
+    shards: Vec<Shard>,
+    top_levels: Vec<Vec<Felt>>,
+}
+
+impl ShardedMerkleTree {
+    /// Partitions allocations with `strategy` and computes the root of every non-empty shard.
+    ///
+    /// Only the roots are kept, so the tree cannot build proofs; use `with_spill` for that.
+    pub fn new(allocations: Vec<Allocation>, strategy: ShardStrategy) -> Result<Self, String> {
+        ShardedMerkleTree::build(allocations, strategy, None)
+    }
+
+    /// Builds the tree like `new`, writing each shard's levels and allocations under `dir`.
+    pub fn with_spill(
+        allocations: Vec<Allocation>,
+        strategy: ShardStrategy,
+        dir: &Path,
+    ) -> Result<Self, String> {
+        ShardedMerkleTree::build(allocations, strategy, Some(dir))
+    }
+
+    fn build(
+        allocations: Vec<Allocation>,
+        strategy: ShardStrategy,
+        dir: Option<&Path>,
+    ) -> Result<Self, String> {
+        if allocations.is_empty() {
+            return Err("Tree has no allocations".to_string());
+        }
+
+        let mut shards = vec![];
+        for (key, allocations) in partition(allocations, strategy)? {
+            shards.push(Shard::build(key, allocations, dir)?);
+        }
+        let top_levels = build_levels(shards.iter().map(Shard::root).collect());
+
+        Ok(ShardedMerkleTree {
+            strategy,
+            shards,
+            top_levels,
+        })
+    }
+
+    /// Returns the root-of-roots, to set on the `Claimer`.
+    pub fn root(&self) -> Felt {
+        self.top_levels.last().unwrap()[0]
+    }
+
+    /// Returns the non-empty shards, ordered by key.
+    pub fn shards(&self) -> &[Shard] {
+        &self.shards
+    }
+
+    /// Finds the shard, as an index in `shards`, and the leaf position of an allocation.
+    ///
+    /// Reads the allocations file of its shard. Under `IndexRange` the shard follows from the
+    /// input index, which an allocation does not carry, so shards are read in turn; use
+    /// `locate_index` when the index is known.
+    pub fn locate(&self, allocation: &Allocation) -> Result<(usize, usize), String> {
+        self.require_spilled()?;
+        let candidates: Vec<usize> = match self.strategy {
+            ShardStrategy::AddressPrefix { bits } => {
+                let (address, _, _, _) = allocation.to_felts()?;
+                let key = address_prefix(&address, bits);
+                self.shards
+                    .iter()
+                    .position(|shard| shard.key == key)
+                    .into_iter()
+                    .collect()
+            }
+            ShardStrategy::IndexRange { .. } => (0..self.shards.len()).collect(),
+        };
+
+        for shard in candidates {
+            for (position, candidate) in self.shards[shard].read_allocations()?.enumerate() {
+                if candidate? == *allocation {
+                    return Ok((shard, position));
+                }
+            }
+        }
+        Err("Allocation not found".to_string())
+    }
+
+    /// Finds the shard and leaf position of the allocation at `index` in the `IndexRange` input.
+    pub fn locate_index(&self, index: usize) -> Result<(usize, usize), String> {
+        let ShardStrategy::IndexRange { size } = self.strategy else {
+            return Err("Allocations are not sharded by index".to_string());
+        };
+        // Every range but the last is full, so shard keys are exactly 0..shards.len()
+        let (shard, position) = (index / size, index % size);
+        if shard >= self.shards.len() || position >= self.shards[shard].leaf_count {
+            return Err("Leaf index out of range".to_string());
+        }
+        Ok((shard, position))
+    }
+
+    /// Collects the sibling hashes from a leaf, given by shard index and position, to the root.
+    pub fn build_proof_at(&self, shard: usize, position: usize) -> Result<Vec<Felt>, String> {
+        self.require_spilled()?;
+        let levels = SpilledLevels::open(
+            self.shards
+                .get(shard)
+                .ok_or("Shard index out of range")?
+                .spill_dir()?,
+        )?;
+        if position >= self.shards[shard].leaf_count {
+            return Err("Leaf index out of range".to_string());
+        }
+
+        let mut proof = levels.proof(position as u64)?;
+        proof.extend(proof_from_levels(&self.top_levels, shard));
+        Ok(proof)
+    }
+
+    /// Collects the sibling hashes from an allocation's leaf up to the root-of-roots.
+    pub fn build_proof(&self, allocation: &Allocation) -> Result<Vec<Felt>, String> {
+        let (shard, position) = self.locate(allocation)?;
+        self.build_proof_at(shard, position)
+    }
+
+    /// Fails for trees built with `new`, which keep no shard levels.
+    fn require_spilled(&self) -> Result<(), String> {
+        match self.shards[0].dir {
+            Some(_) => Ok(()),
+            None => Err(
+                "Sharded tree was built without spilling; build it with with_spill to get proofs"
+                    .to_string(),
+            ),
+        }
+    }
+
+    /// Generates `[address, amount, timestamp, id, ...proof]` calldata for an allocation.
+    pub fn build_address_calldata(
+        &self,
+        address: &str,
+        amount: u64,
+        timestamp: &str,
+        id: u64,
+    ) -> Result<Vec<String>, String> {
+        let allocation = Allocation {
+            address: address.to_string(),
+            amount,
+            timestamp: timestamp.to_string(),
+            id,
+        };
+
+        let proof = self.build_proof(&allocation)?;
+        let felts = allocation.to_felts()?;
+        let mut calldata = vec![felts.0, felts.1, felts.2, felts.3];
+        calldata.extend(proof);
+
+        Ok(calldata.iter().map(|f| format!("{:#x}", f)).collect())
+    }
+}
+
+/// Groups allocations by shard key, keeping their input order within each shard.
+fn partition(
+    allocations: Vec<Allocation>,
+    strategy: ShardStrategy,
+) -> Result<BTreeMap<usize, Vec<Allocation>>, String> {
+    let mut shards: BTreeMap<usize, Vec<Allocation>> = BTreeMap::new();
+    match strategy {
+        ShardStrategy::AddressPrefix { bits } => {
+            if !(1..=16).contains(&bits) {
+                return Err("Address prefix must be between 1 and 16 bits".to_string());
+            }
+            for allocation in allocations {
+                let (address, _, _, _) = allocation.to_felts()?;
+                shards
+                    .entry(address_prefix(&address, bits))
+                    .or_default()
+                    .push(allocation);
+            }
+        }
+        ShardStrategy::IndexRange { size } => {
+            if size == 0 {
+                return Err("Shard size must be positive".to_string());
+            }
+            for (i, allocation) in allocations.into_iter().enumerate() {
+                shards.entry(i / size).or_default().push(allocation);
+            }
+        }
+    }
+    Ok(shards)
+}
+
+/// Reads the top `bits` bits of a felt, counted from bit 251.
+fn address_prefix(address: &Felt, bits: u32) -> usize {
+    let bytes = address.to_bytes_be();
+    let top = u64::from_be_bytes(bytes[..8].try_into().unwrap()) << 4;
+    (top >> (64 - bits)) as usize
+}
+
+/// Writes one JSON allocation per line, the format `read_json_lines` reads.
+fn write_allocations(path: &Path, allocations: &[Allocation]) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("Failed to create {:?}: {}", path, e))?;
+    let mut writer = BufWriter::new(file);
+    for allocation in allocations {
+        let line = serde_json::to_string(allocation).map_err(|e| e.to_string())?;
+        writeln!(writer, "{}", line).map_err(|e| format!("Failed to write {:?}: {}", path, e))?;
+    }
+    writer.flush().map_err(|e| e.to_string())
+}
+
+fn allocations_path(dir: &Path) -> PathBuf {
+    dir.join("allocations.jsonl")
+}
@@ -0,0 +1,210 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::{LeafOrdering, MerkleTree};
+use crate::core::node::Node;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use std::collections::HashMap;
+
+/// `format` of OpenZeppelin `StandardMerkleTree` dumps.
+pub const STANDARD_FORMAT: &str = "standard-v1";
+
+/// Cairo types of the leaf values, as `Claimer::claim` hashes them.
+pub const LEAF_ENCODING: [&str; 4] = ["ContractAddress", "u128", "u128", "u128"];
+
+/// A leaf value of a dump and the position of its leaf in `tree`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StandardValue {
+    /// Address and timestamp as written in the allocation, then amount and id in decimal.
+    pub value: Vec<String>,
+    pub tree_index: usize,
+}
+
+/// Tree in the JSON layout of OpenZeppelin's `StandardMerkleTree.dump()`.
+///
+/// `tree` is a heap: node `i` has children `2i + 1` and `2i + 2` and `tree[0]` is the root.
+/// Padding leaves and self-paired nodes appear as copies, so every node is the sorted-pair
+/// Pedersen hash of its children and the usual heap walk yields `Claimer` proofs.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StandardMerkleTreeDump {
+    pub format: String,
+    pub leaf_encoding: Vec<String>,
+    pub tree: Vec<String>,
+    pub values: Vec<StandardValue>,
+    /// Ordering the values were laid out with; OpenZeppelin ignores it and dumps without it
+    /// import as `Insertion`.
+    #[serde(default)]
+    pub ordering: LeafOrdering,
+}
+
+impl MerkleTree {
+    /// Exports the tree as a `StandardMerkleTree` dump.
+    pub fn to_standard_dump(&self) -> Result<StandardMerkleTreeDump, String> {
+        let mut tree = vec![];
+        let mut leaf_indexes = HashMap::new();
+        fill_heap(&self.root, 0, &mut tree, &mut leaf_indexes);
+
+        let values = self
+            .get_allocations()
+            .iter()
+            .map(|allocation| {
+                let tree_index = *leaf_indexes.get(allocation).ok_or("Allocation not found")?;
+                Ok(StandardValue {
+                    value: encode_value(allocation)?,
+                    tree_index,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(StandardMerkleTreeDump {
+            format: STANDARD_FORMAT.to_string(),
+            leaf_encoding: LEAF_ENCODING.iter().map(|t| t.to_string()).collect(),
+            tree: tree.iter().map(|hash| format!("{:#x}", hash)).collect(),
+            values,
+            ordering: self.ordering(),
+        })
+    }
+
+    /// Imports a dump written by `to_standard_dump`.
+    ///
+    /// The tree is rebuilt from the values with the recorded ordering and must match the dumped
+    /// nodes and leaf positions exactly.
+    pub fn from_standard_dump(dump: &StandardMerkleTreeDump) -> Result<MerkleTree, String> {
+        if dump.format != STANDARD_FORMAT {
+            return Err(format!("Unsupported format: {}", dump.format));
+        }
+        check_leaf_encoding(&dump.leaf_encoding)?;
+        if dump.values.is_empty() {
+            return Err("Tree has no allocations".to_string());
+        }
+
+        let allocations = dump
+            .values
+            .iter()
+            .map(|value| decode_value(&value.value))
+            .collect::<Result<Vec<_>, _>>()?;
+        let tree = MerkleTree::with_ordering(allocations, dump.ordering);
+
+        let rebuilt = tree.to_standard_dump()?;
+        let dumped_nodes = dump
+            .tree
+            .iter()
+            .map(|hash| Felt::from_hex(hash).map_err(|_| format!("Invalid tree hash: {}", hash)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let rebuilt_nodes: Vec<Felt> = rebuilt
+            .tree
+            .iter()
+            .map(|hash| Felt::from_hex(hash).unwrap())
+            .collect();
+        if dumped_nodes != rebuilt_nodes {
+            return Err("Tree does not match values".to_string());
+        }
+        for (dumped, rebuilt) in dump.values.iter().zip(rebuilt.values.iter()) {
+            if dumped.tree_index != rebuilt.tree_index {
+                return Err(format!("Invalid tree index: {}", dumped.tree_index));
+            }
+        }
+
+        Ok(tree)
+    }
+}
+
+/// Writes a subtree into the heap, recording the first heap index of each leaf's allocation.
+fn fill_heap<'a>(
+    node: &'a Node,
+    index: usize,
+    tree: &mut Vec<Felt>,
+    leaf_indexes: &mut HashMap<&'a Allocation, usize>,
+) {
+    if tree.len() <= index {
+        tree.resize(index + 1, Felt::ZERO);
+    }
+    tree[index] = node.value;
+
+    match (&node.left_child, &node.right_child) {
+        (Some(left), Some(right)) => {
+            fill_heap(left, 2 * index + 1, tree, leaf_indexes);
+            fill_heap(right, 2 * index + 2, tree, leaf_indexes);
+        }
+        _ => {
+            for allocation in node.accessible_allocations.iter() {
+                leaf_indexes.entry(allocation).or_insert(index);
+            }
+        }
+    }
+}
+
+fn check_leaf_encoding(leaf_encoding: &[String]) -> Result<(), String> {
+    // `felt252` hashes like any of the encoded types, so it is accepted in every position
+    let supported = leaf_encoding.len() == LEAF_ENCODING.len()
+        && leaf_encoding
+            .iter()
+            .zip(LEAF_ENCODING.iter())
+            .all(|(encoding, expected)| encoding == expected || encoding == "felt252");
+
+    if !supported {
+        return Err(format!(
+            "Unsupported leaf encoding: [{}]",
+            leaf_encoding.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+fn encode_value(allocation: &Allocation) -> Result<Vec<String>, String> {
+    let (_, amount, timestamp, id) = allocation.to_felts()?;
+    if timestamp.bits() > 128 {
+        return Err("Timestamp does not fit in u128".to_string());
+    }
+
+    // address and timestamp keep their spelling so an import gives back the same allocations
+    Ok(vec![
+        allocation.address.clone(),
+        amount.to_string(),
+        allocation.timestamp.clone(),
+        id.to_string(),
+    ])
+}
+
+fn decode_value(value: &[String]) -> Result<Allocation, String> {
+    let [address, amount, timestamp, id] = value else {
+        return Err(format!("Expected 4 values, got {}", value.len()));
+    };
+
+    Felt::from_hex(address).map_err(|_| "Invalid address")?;
+    let parsed = parse_number(timestamp, 128).map_err(|_| "Invalid timestamp")?;
+    // allocations hold hex timestamps, so only decimal ones written by other tools are converted
+    let timestamp = if timestamp.starts_with("0x") {
+        timestamp.clone()
+    } else {
+        format!("{:#x}", parsed)
+    };
+    Ok(Allocation {
+        address: address.clone(),
+        amount: parse_u64(amount).map_err(|_| "Invalid amount")?,
+        timestamp,
+        id: parse_u64(id).map_err(|_| "Invalid id")?,
+    })
+}
+
+fn parse_u64(value: &str) -> Result<u64, ()> {
+    let value = parse_number(value, 64)?;
+    let bytes = value.to_bytes_be();
+    Ok(u64::from_be_bytes(bytes[24..].try_into().unwrap()))
+}
+
+/// Parses a decimal or `0x` hex number of at most `bits` bits.
+fn parse_number(value: &str, bits: u64) -> Result<Felt, ()> {
+    let number = if value.starts_with("0x") {
+        Felt::from_hex(value)
+    } else {
+        Felt::from_dec_str(value)
+    }
+    .map_err(|_| ())?;
+
+    if number.bits() as u64 > bits {
+        return Err(());
+    }
+    Ok(number)
+}
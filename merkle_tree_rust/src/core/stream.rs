@@ -0,0 +1,264 @@
+use crate::core::allocation::Allocation;
+use crate::core::proof::hash_pair;
+use starknet::core::types::Felt;
+use std::fs::{self, File};
+use std::io::{BufRead, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Size of a hash in a level file, stored big-endian.
+const HASH_SIZE: u64 = 32;
+
+/// Computes the root of a `MerkleTree` without holding its allocations or nodes.
+///
+/// Leaves are folded into a frontier holding at most one pending node per level, so memory
+/// stays O(log n). With `with_spill`, every level is also appended to a file in a directory
+/// that `SpilledLevels` reads back to build proofs.
+pub struct StreamingTreeBuilder {
+    /// Left node waiting for its sibling at each level.
+    frontier: Vec<Option<Felt>>,
+    leaf_count: u64,
+    last_leaf: Option<Felt>,
+    spill: Option<LevelWriter>,
+}
+
+impl Default for StreamingTreeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StreamingTreeBuilder {
+    /// Creates a builder that only computes the root.
+    pub fn new() -> Self {
+        StreamingTreeBuilder {
+            frontier: vec![],
+            leaf_count: 0,
+            last_leaf: None,
+            spill: None,
+        }
+    }
+
+    /// Creates a builder that also writes every level hash to `dir`.
+    pub fn with_spill(dir: &Path) -> Result<Self, String> {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {:?}: {}", dir, e))?;
+
+        // Levels left over by a taller tree would be read back as part of this one
+        let mut level = 0;
+        while fs::remove_file(level_path(dir, level)).is_ok() {
+            level += 1;
+        }
+
+        Ok(StreamingTreeBuilder {
+            spill: Some(LevelWriter {
+                dir: dir.to_path_buf(),
+                files: vec![],
+            }),
+            ..Self::new()
+        })
+    }
+
+    /// Adds the next allocation, in the order `MerkleTree::new` would see it.
+    pub fn push(&mut self, allocation: &Allocation) -> Result<(), String> {
+        self.push_leaf(allocation.leaf_hash()?)
+    }
+
+    /// Adds the next leaf hash.
+    pub fn push_leaf(&mut self, leaf: Felt) -> Result<(), String> {
+        self.leaf_count += 1;
+        self.last_leaf = Some(leaf);
+        self.insert(leaf)
+    }
+
+    /// Returns the number of leaves pushed so far.
+    pub fn leaf_count(&self) -> u64 {
+        self.leaf_count
+    }
+
+    /// Completes the tree and returns its root.
+    pub fn finalize(mut self) -> Result<Felt, String> {
+        let last_leaf = self.last_leaf.ok_or("Tree has no allocations")?;
+
+        // Odd leaf counts duplicate the last leaf, as `MerkleTree::new` does
+        if self.leaf_count % 2 == 1 {
+            self.insert(last_leaf)?;
+        }
+
+        let mut carry: Option<Felt> = None;
+        let mut level = 0;
+        let root = loop {
+            let node = match (self.frontier.get(level).copied().flatten(), carry) {
+                (Some(left), Some(right)) => hash_pair(&left, &right),
+                (Some(node), None) | (None, Some(node)) => {
+                    if self.frontier.iter().skip(level + 1).all(Option::is_none) {
+                        break node;
+                    }
+                    // The last node of an odd level is paired with itself
+                    hash_pair(&node, &node)
+                }
+                (None, None) => {
+                    level += 1;
+                    continue;
+                }
+            };
+            self.write(level + 1, &node)?;
+            carry = Some(node);
+            level += 1;
+        };
+
+        if let Some(spill) = self.spill.as_mut() {
+            spill.flush()?;
+        }
+        Ok(root)
+    }
+
+    fn insert(&mut self, leaf: Felt) -> Result<(), String> {
+        self.write(0, &leaf)?;
+
+        let mut node = leaf;
+        let mut level = 0;
+        loop {
+            if self.frontier.len() == level {
+                self.frontier.push(None);
+            }
+            match self.frontier[level].take() {
+                Some(left) => {
+                    node = hash_pair(&left, &node);
+                    level += 1;
+                    self.write(level, &node)?;
+                }
+                None => {
+                    self.frontier[level] = Some(node);
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn write(&mut self, level: usize, hash: &Felt) -> Result<(), String> {
+        match self.spill.as_mut() {
+            Some(spill) => spill.write(level, hash),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Appends hashes to one file per level.
+struct LevelWriter {
+    dir: PathBuf,
+    files: Vec<BufWriter<File>>,
+}
+
+impl LevelWriter {
+    fn write(&mut self, level: usize, hash: &Felt) -> Result<(), String> {
+        while self.files.len() <= level {
+            let path = level_path(&self.dir, self.files.len());
+            let file =
+                File::create(&path).map_err(|e| format!("Failed to create {:?}: {}", path, e))?;
+            self.files.push(BufWriter::new(file));
+        }
+        self.files[level]
+            .write_all(&hash.to_bytes_be())
+            .map_err(|e| format!("Failed to write level {}: {}", level, e))
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        for file in self.files.iter_mut() {
+            file.flush().map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// Level hashes spilled by a `StreamingTreeBuilder`, read from disk on demand.
+pub struct SpilledLevels {
+    dir: PathBuf,
+    /// Number of hashes in each level, the leaves first.
+    lengths: Vec<u64>,
+}
+
+impl SpilledLevels {
+    /// Opens the level files of a directory written by `StreamingTreeBuilder::with_spill`.
+    pub fn open(dir: &Path) -> Result<Self, String> {
+        let mut lengths = vec![];
+        loop {
+            let path = level_path(dir, lengths.len());
+            let Ok(metadata) = fs::metadata(&path) else {
+                break;
+            };
+            if metadata.len() % HASH_SIZE != 0 {
+                return Err(format!("Truncated level file {:?}", path));
+            }
+            lengths.push(metadata.len() / HASH_SIZE);
+        }
+
+        if lengths.last() != Some(&1) {
+            return Err(format!("No complete tree in {:?}", dir));
+        }
+        Ok(SpilledLevels {
+            dir: dir.to_path_buf(),
+            lengths,
+        })
+    }
+
+    /// Returns the root of the spilled tree.
+    pub fn root(&self) -> Result<Felt, String> {
+        self.read(self.lengths.len() - 1, 0)
+    }
+
+    /// Returns the leaf hash at a position, including the duplicated padding leaf.
+    pub fn leaf(&self, index: u64) -> Result<Felt, String> {
+        self.read(0, index)
+    }
+
+    /// Collects the sibling hashes of the leaf at a position of the input.
+    pub fn proof(&self, mut index: u64) -> Result<Vec<Felt>, String> {
+        if index >= self.lengths[0] {
+            return Err("Leaf index out of range".to_string());
+        }
+
+        let mut proof = vec![];
+        for (level, &length) in self.lengths[..self.lengths.len() - 1].iter().enumerate() {
+            let sibling = if index ^ 1 < length { index ^ 1 } else { index };
+            proof.push(self.read(level, sibling)?);
+            index /= 2;
+        }
+        Ok(proof)
+    }
+
+    fn read(&self, level: usize, index: u64) -> Result<Felt, String> {
+        let path = level_path(&self.dir, level);
+        let mut file =
+            File::open(&path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+        let mut bytes = [0u8; HASH_SIZE as usize];
+        file.seek(SeekFrom::Start(index * HASH_SIZE))
+            .and_then(|_| file.read_exact(&mut bytes))
+            .map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+        Ok(Felt::from_bytes_be(&bytes))
+    }
+}
+
+/// Streams the root of a sequence of allocations.
+pub fn stream_root(allocations: impl IntoIterator<Item = Allocation>) -> Result<Felt, String> {
+    let mut builder = StreamingTreeBuilder::new();
+    for allocation in allocations {
+        builder.push(&allocation)?;
+    }
+    builder.finalize()
+}
+
+/// Reads one JSON allocation per line, skipping blank lines.
+pub fn read_json_lines<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Allocation, String>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|(i, line)| {
+            let line = line.map_err(|e| format!("Failed to read line {}: {}", i + 1, e))?;
+            serde_json::from_str(&line)
+                .map_err(|e| format!("Invalid allocation on line {}: {}", i + 1, e))
+        })
+}
+
+fn level_path(dir: &Path, level: usize) -> PathBuf {
+    dir.join(format!("level_{}.bin", level))
+}
@@ -0,0 +1,150 @@
+use crate::core::allocation::Allocation;
+use crate::core::proof::{hash_pair, leaf_positions, proof_from_levels};
+use serde::{Deserialize, Serialize};
+use starknet::core::types::Felt;
+use starknet_crypto::pedersen_hash;
+use std::collections::HashMap;
+
+/// A node committing to a hash and to the total amount of the leaves below it.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SumNode {
+    pub hash: Felt,
+    pub sum: u128,
+}
+
+impl SumNode {
+    /// Node used to pad odd levels; it adds nothing to the total.
+    pub const EMPTY: SumNode = SumNode {
+        hash: Felt::ZERO,
+        sum: 0,
+    };
+
+    /// Creates the leaf of an allocation: its `Claimer` leaf hash and its amount.
+    pub fn new_leaf(allocation: &Allocation) -> Result<Self, String> {
+        Ok(SumNode {
+            hash: allocation.leaf_hash()?,
+            sum: allocation.amount as u128,
+        })
+    }
+}
+
+/// Merkle sum tree over allocations, whose root commits to the campaign total.
+///
+/// Children are hashed in sorted order like the plain tree, then the pair hash is hashed with
+/// the subtotal: `pedersen(pedersen(min, max), left.sum + right.sum)`. Odd levels are padded
+/// with `SumNode::EMPTY` rather than a duplicate, which would count an amount twice.
+pub struct MerkleSumTree {
+    allocations: Vec<Allocation>,
+    levels: Vec<Vec<SumNode>>,
+    leaf_index: HashMap<Allocation, usize>,
+}
+
+impl MerkleSumTree {
+    /// Creates a sum tree from a list of allocations.
+    pub fn new(allocations: Vec<Allocation>) -> Result<Self, String> {
+        if allocations.is_empty() {
+            return Err("Tree has no allocations".to_string());
+        }
+
+        let leaves = allocations
+            .iter()
+            .map(SumNode::new_leaf)
+            .collect::<Result<Vec<_>, _>>()?;
+        let levels = build_levels(leaves)?;
+
+        Ok(MerkleSumTree {
+            leaf_index: leaf_positions(&allocations),
+            allocations,
+            levels,
+        })
+    }
+
+    /// Returns the root, whose `sum` is the total of every allocation.
+    pub fn root(&self) -> SumNode {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns the list of allocations in the tree.
+    pub fn get_allocations(&self) -> &Vec<Allocation> {
+        &self.allocations
+    }
+
+    /// Collects the sibling nodes, with their subtotals, from an allocation's leaf to the root.
+    pub fn build_proof(&self, allocation: &Allocation) -> Result<Vec<SumNode>, String> {
+        let index = *self
+            .leaf_index
+            .get(allocation)
+            .ok_or("Allocation not found")?;
+        Ok(proof_from_levels(&self.levels, index))
+    }
+
+    /// Generates `[address, amount, timestamp, id, hash_0, sum_0, hash_1, sum_1, ...]` calldata.
+    pub fn build_address_calldata(
+        &self,
+        address: &str,
+        amount: u64,
+        timestamp: &str,
+        id: u64,
+    ) -> Result<Vec<String>, String> {
+        let allocation = Allocation {
+            address: address.to_string(),
+            amount,
+            timestamp: timestamp.to_string(),
+            id,
+        };
+
+        let proof = self.build_proof(&allocation)?;
+        let felts = allocation.to_felts()?;
+        let mut calldata = vec![felts.0, felts.1, felts.2, felts.3];
+        for node in proof {
+            calldata.push(node.hash);
+            calldata.push(Felt::from(node.sum));
+        }
+
+        Ok(calldata.iter().map(|f| format!("{:#x}", f)).collect())
+    }
+}
+
+/// Combines two nodes, failing if their subtotals overflow a `u128`.
+pub fn hash_sum_pair(a: &SumNode, b: &SumNode) -> Result<SumNode, String> {
+    let sum = a.sum.checked_add(b.sum).ok_or("Subtotal overflow")?;
+    Ok(SumNode {
+        hash: pedersen_hash(&hash_pair(&a.hash, &b.hash), &Felt::from(sum)),
+        sum,
+    })
+}
+
+/// Folds a proof into the root it commits to, adding up subtotals along the path.
+pub fn compute_sum_root(leaf: SumNode, proof: &[SumNode]) -> Result<SumNode, String> {
+    proof
+        .iter()
+        .try_fold(leaf, |current, sibling| hash_sum_pair(&current, sibling))
+}
+
+/// Checks that a leaf and its proof resolve to the expected root hash and total.
+pub fn verify_sum_proof(root: &SumNode, leaf: SumNode, proof: &[SumNode]) -> bool {
+    compute_sum_root(leaf, proof).is_ok_and(|computed| computed == *root)
+}
+
+/// Builds every level of the tree, from the padded leaves up to the root.
+fn build_levels(mut leaves: Vec<SumNode>) -> Result<Vec<Vec<SumNode>>, String> {
+    // A lone leaf is still paired so that the root always commits to a subtotal
+    if leaves.len() % 2 == 1 {
+        leaves.push(SumNode::EMPTY);
+    }
+
+    let mut levels = vec![leaves];
+    while levels.last().unwrap().len() > 1 {
+        let level = levels.last_mut().unwrap();
+        if level.len() % 2 == 1 {
+            level.push(SumNode::EMPTY);
+        }
+
+        let next_level = level
+            .chunks(2)
+            .map(|pair| hash_sum_pair(&pair[0], &pair[1]))
+            .collect::<Result<Vec<_>, _>>()?;
+        levels.push(next_level);
+    }
+    Ok(levels)
+}
@@ -0,0 +1,133 @@
+use crate::core::allocation::u64_to_felt;
+use crate::core::proof::{build_leaf_levels, encode_calldata, leaf_positions, proof_from_levels};
+use serde::{Deserialize, Serialize};
+use starknet::core::types::{Call, Felt};
+use starknet::core::utils::get_selector_from_name;
+use starknet_crypto::pedersen_hash;
+use std::collections::HashMap;
+
+/// `IVestingClaimer` entry point that releases the vested part of a schedule.
+pub const RELEASE_ENTRYPOINT: &str = "release";
+
+/// A grant that vests linearly from `start` to `start + duration`, with nothing before the cliff.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VestingSchedule {
+    pub beneficiary: String,
+    pub total: u64,
+    /// Unix timestamp, in seconds, at which vesting starts.
+    pub start: u64,
+    /// Seconds after `start` before anything can be released.
+    pub cliff: u64,
+    /// Seconds after `start` at which the whole `total` is vested.
+    pub duration: u64,
+    pub id: u64,
+}
+
+impl VestingSchedule {
+    /// Converts `[beneficiary, total, start, cliff, duration, id]` into Felt values for hashing.
+    pub fn to_felts(&self) -> Result<[Felt; 6], String> {
+        let beneficiary = Felt::from_hex(&self.beneficiary).map_err(|_| "Invalid beneficiary")?;
+        if self.cliff > self.duration {
+            return Err("Cliff is longer than duration".to_string());
+        }
+
+        Ok([
+            beneficiary,
+            u64_to_felt(self.total),
+            u64_to_felt(self.start),
+            u64_to_felt(self.cliff),
+            u64_to_felt(self.duration),
+            u64_to_felt(self.id),
+        ])
+    }
+
+    /// Computes the leaf hash checked by the `VestingClaimer` contract.
+    ///
+    /// Fields are chained with Pedersen like an `Allocation` leaf, so the nodes above are the same.
+    pub fn leaf_hash(&self) -> Result<Felt, String> {
+        let felts = self.to_felts()?;
+        Ok(felts[1..]
+            .iter()
+            .fold(felts[0], |hash, field| pedersen_hash(&hash, field)))
+    }
+
+    /// Returns the amount vested at `timestamp`.
+    pub fn vested_amount(&self, timestamp: u64) -> u64 {
+        let elapsed = timestamp.saturating_sub(self.start);
+        if timestamp < self.start || elapsed < self.cliff {
+            return 0;
+        }
+        if elapsed >= self.duration {
+            return self.total;
+        }
+        (self.total as u128 * elapsed as u128 / self.duration as u128) as u64
+    }
+
+    /// Returns what a beneficiary who already released `released` can release at `timestamp`.
+    pub fn releasable_amount(&self, timestamp: u64, released: u64) -> u64 {
+        self.vested_amount(timestamp).saturating_sub(released)
+    }
+}
+
+/// Merkle tree over vesting schedules, hashed like `MerkleTree`.
+pub struct VestingTree {
+    schedules: Vec<VestingSchedule>,
+    levels: Vec<Vec<Felt>>,
+    leaf_index: HashMap<VestingSchedule, usize>,
+}
+
+impl VestingTree {
+    /// Creates a tree from a list of schedules, duplicating the last leaf of an odd list.
+    pub fn new(schedules: Vec<VestingSchedule>) -> Result<Self, String> {
+        if schedules.is_empty() {
+            return Err("Tree has no schedules".to_string());
+        }
+
+        let leaves = schedules
+            .iter()
+            .map(VestingSchedule::leaf_hash)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(VestingTree {
+            leaf_index: leaf_positions(&schedules),
+            levels: build_leaf_levels(leaves),
+            schedules,
+        })
+    }
+
+    /// Returns the root to store in the `VestingClaimer`.
+    pub fn root(&self) -> Felt {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns the list of schedules in the tree.
+    pub fn get_schedules(&self) -> &Vec<VestingSchedule> {
+        &self.schedules
+    }
+
+    /// Collects the sibling hashes from a schedule's leaf up to the root.
+    pub fn build_proof(&self, schedule: &VestingSchedule) -> Result<Vec<Felt>, String> {
+        let index = *self.leaf_index.get(schedule).ok_or("Schedule not found")?;
+        Ok(proof_from_levels(&self.levels, index))
+    }
+
+    /// Builds a `VestingClaimer::release(total, start, cliff, duration, id, proof)` call.
+    ///
+    /// The contract reads the beneficiary from the caller and checks the proof once per root, so
+    /// later calls under the same root may pass an empty proof.
+    pub fn build_release_call(
+        &self,
+        contract_address: &str,
+        schedule: &VestingSchedule,
+    ) -> Result<Call, String> {
+        let to = Felt::from_hex(contract_address).map_err(|_| "Invalid contract address")?;
+        let felts = schedule.to_felts()?;
+        let proof = self.build_proof(schedule)?;
+
+        Ok(Call {
+            to,
+            selector: get_selector_from_name(RELEASE_ENTRYPOINT).unwrap(),
+            calldata: encode_calldata(&felts[1..], proof),
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use crate::core::proof::{build_leaf_levels_with, leaf_positions, proof_from_levels};
+use sha3::{Digest, Keccak256};
+use std::collections::HashMap;
+
+/// A 32-byte EVM word, Solidity's `bytes32`.
+pub type Bytes32 = [u8; 32];
+
+/// Hashes an allocation as `keccak256(abi.encode(address, amount, timestamp, id))`.
+///
+/// Every value is encoded as a 32-byte big-endian word, so the address must fit in 20 bytes.
+pub fn leaf_hash(allocation: &Allocation) -> Result<Bytes32, String> {
+    let (address, amount, timestamp, id) = allocation.to_felts()?;
+    if address.bits() > 160 {
+        return Err("Address does not fit in 20 bytes".to_string());
+    }
+
+    let mut hasher = Keccak256::new();
+    for word in [address, amount, timestamp, id] {
+        hasher.update(word.to_bytes_be());
+    }
+    Ok(hasher.finalize().into())
+}
+
+/// Hashes two nodes in sorted order, as OpenZeppelin's `MerkleProof` does.
+pub fn hash_pair(a: &Bytes32, b: &Bytes32) -> Bytes32 {
+    let (min, max) = if a <= b { (a, b) } else { (b, a) };
+    Keccak256::new()
+        .chain_update(min)
+        .chain_update(max)
+        .finalize()
+        .into()
+}
+
+/// Folds a proof into the root it leads to.
+pub fn compute_root(leaf: Bytes32, proof: &[Bytes32]) -> Bytes32 {
+    proof
+        .iter()
+        .fold(leaf, |current, sibling| hash_pair(&current, sibling))
+}
+
+/// Checks a proof as `MerkleProof.verify` does on chain.
+pub fn verify_proof(root: Bytes32, leaf: Bytes32, proof: &[Bytes32]) -> bool {
+    compute_root(leaf, proof) == root
+}
+
+/// Formats a word as `0x` followed by 64 hex digits.
+pub fn to_hex(word: &Bytes32) -> String {
+    let digits: String = word.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("0x{}", digits)
+}
+
+/// Parses a `0x`-prefixed word of 64 hex digits.
+pub fn parse_bytes32(hex: &str) -> Result<Bytes32, String> {
+    let digits = hex
+        .strip_prefix("0x")
+        .filter(|digits| digits.len() == 64 && digits.is_ascii())
+        .ok_or(format!("Invalid bytes32: {}", hex))?;
+
+    let mut word = [0u8; 32];
+    for (i, byte) in word.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[2 * i..2 * i + 2], 16)
+            .map_err(|_| format!("Invalid bytes32: {}", hex))?;
+    }
+    Ok(word)
+}
+
+/// Keccak tree of a distribution, for claims verified with OpenZeppelin's `MerkleProof`.
+///
+/// Leaves are laid out like `MerkleTree`: padded to an even count, odd levels pairing their
+/// last node with itself.
+pub struct EvmMerkleTree {
+    allocations: Vec<Allocation>,
+    levels: Vec<Vec<Bytes32>>,
+    leaf_index: HashMap<Allocation, usize>,
+}
+
+impl EvmMerkleTree {
+    /// Builds the tree with the allocations in the given order.
+    pub fn new(allocations: Vec<Allocation>) -> Result<Self, String> {
+        if allocations.is_empty() {
+            return Err("Tree has no allocations".to_string());
+        }
+
+        let leaves = allocations
+            .iter()
+            .map(leaf_hash)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(EvmMerkleTree {
+            leaf_index: leaf_positions(&allocations),
+            allocations,
+            levels: build_leaf_levels_with(leaves, hash_pair),
+        })
+    }
+
+    /// Builds the EVM tree of a Starknet tree, keeping its leaf order.
+    pub fn from_tree(tree: &MerkleTree) -> Result<Self, String> {
+        EvmMerkleTree::new(tree.get_allocations().clone())
+    }
+
+    /// Returns the root, to set on the EVM claim contract.
+    pub fn root(&self) -> Bytes32 {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns the allocations in leaf order.
+    pub fn get_allocations(&self) -> &Vec<Allocation> {
+        &self.allocations
+    }
+
+    /// Returns the siblings of an allocation's leaf, from the leaf up.
+    pub fn proof(&self, allocation: &Allocation) -> Result<Vec<Bytes32>, String> {
+        let index = self
+            .leaf_index
+            .get(allocation)
+            .ok_or("Allocation not found")?;
+
+        Ok(proof_from_levels(&self.levels, *index))
+    }
+
+    /// Returns the proof of an allocation as the hex strings of a `bytes32[]` argument.
+    pub fn proof_hex(&self, allocation: &Allocation) -> Result<Vec<String>, String> {
+        Ok(self.proof(allocation)?.iter().map(to_hex).collect())
+    }
+}
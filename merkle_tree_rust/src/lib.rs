@@ -1,9 +1,39 @@
 pub mod core {
+    pub mod aggregate;
     pub mod allocation;
+    pub mod attestation;
+    pub mod campaign;
+    pub mod claims;
+    pub mod diff;
+    pub mod ids;
+    pub mod manifest;
     pub mod merkle_tree;
+    pub mod mmr;
     pub mod node;
+    pub mod proof;
+    pub mod render;
+    pub mod sharded;
+    pub mod standard_tree;
+    pub mod stream;
+    pub mod sum_tree;
+    pub mod vesting;
 }
 
+#[cfg(feature = "server")]
+pub mod server;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+#[cfg(feature = "evm")]
+pub mod evm;
+
 // pub mod tests;
 
 // pub mod utils;
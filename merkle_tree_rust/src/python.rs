@@ -0,0 +1,188 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use crate::core::proof::{parse_proof, verify_proof};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use starknet::core::types::Felt;
+
+/// Python view of an `Allocation`.
+#[pyclass(name = "Allocation", module = "merkle_tree")]
+#[derive(Clone)]
+pub struct PyAllocation {
+    inner: Allocation,
+}
+
+#[pymethods]
+impl PyAllocation {
+    #[new]
+    fn new(address: String, amount: u64, timestamp: String, id: u64) -> Self {
+        PyAllocation {
+            inner: Allocation {
+                address,
+                amount,
+                timestamp,
+                id,
+            },
+        }
+    }
+
+    #[getter]
+    fn address(&self) -> String {
+        self.inner.address.clone()
+    }
+
+    #[getter]
+    fn amount(&self) -> u64 {
+        self.inner.amount
+    }
+
+    #[getter]
+    fn timestamp(&self) -> String {
+        self.inner.timestamp.clone()
+    }
+
+    #[getter]
+    fn id(&self) -> u64 {
+        self.inner.id
+    }
+
+    /// Computes the leaf hash checked by the `Claimer` contract, as a hex string.
+    fn leaf_hash(&self) -> PyResult<String> {
+        let leaf = self.inner.leaf_hash().map_err(PyValueError::new_err)?;
+        Ok(format!("{:#x}", leaf))
+    }
+
+    fn __eq__(&self, other: &PyAllocation) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Allocation(address='{}', amount={}, timestamp='{}', id={})",
+            self.inner.address, self.inner.amount, self.inner.timestamp, self.inner.id
+        )
+    }
+}
+
+/// Python handle on a `MerkleTree`.
+#[pyclass(name = "MerkleTree", module = "merkle_tree")]
+pub struct PyMerkleTree {
+    tree: MerkleTree,
+}
+
+#[pymethods]
+impl PyMerkleTree {
+    /// Builds a tree, raising `ValueError` on invalid allocations instead of panicking.
+    #[new]
+    fn new(allocations: Vec<PyAllocation>) -> PyResult<Self> {
+        let allocations: Vec<Allocation> = allocations.into_iter().map(|a| a.inner).collect();
+        if allocations.is_empty() {
+            return Err(PyValueError::new_err("Tree has no allocations"));
+        }
+        for allocation in allocations.iter() {
+            allocation.to_felts().map_err(PyValueError::new_err)?;
+        }
+
+        Ok(PyMerkleTree {
+            tree: MerkleTree::new(allocations),
+        })
+    }
+
+    /// Loads a tree serialized with `to_json`, checking its root.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        let tree = serde_json::from_str(json).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyMerkleTree { tree })
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.tree).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// Root of the tree as a hex string, as passed to `set_merkle_root`.
+    #[getter]
+    fn root(&self) -> String {
+        format!("{:#x}", self.tree.root.value)
+    }
+
+    fn allocations(&self) -> Vec<PyAllocation> {
+        self.tree
+            .get_allocations()
+            .iter()
+            .map(|allocation| PyAllocation {
+                inner: allocation.clone(),
+            })
+            .collect()
+    }
+
+    fn allocations_for(&self, address: &str) -> Vec<PyAllocation> {
+        self.tree
+            .allocations_for(address)
+            .into_iter()
+            .map(|allocation| PyAllocation {
+                inner: allocation.clone(),
+            })
+            .collect()
+    }
+
+    fn build_address_calldata(
+        &self,
+        address: &str,
+        amount: u64,
+        timestamp: &str,
+        id: u64,
+    ) -> PyResult<Vec<String>> {
+        self.tree
+            .build_address_calldata(address, amount, timestamp, id)
+            .map_err(PyValueError::new_err)
+    }
+
+    fn proofs_for(&self, address: &str) -> PyResult<Vec<Vec<String>>> {
+        self.tree.proofs_for(address).map_err(PyValueError::new_err)
+    }
+
+    /// Merges new allocations into a new tree, keeping the leaf ordering of this one.
+    fn merge_merkle_trees(&self, new_allocations: Vec<PyAllocation>) -> PyResult<Self> {
+        let new_allocations: Vec<Allocation> =
+            new_allocations.into_iter().map(|a| a.inner).collect();
+        for allocation in new_allocations.iter() {
+            allocation.to_felts().map_err(PyValueError::new_err)?;
+        }
+
+        Ok(PyMerkleTree {
+            tree: self.tree.merge_merkle_trees(new_allocations),
+        })
+    }
+}
+
+/// Folds a proof into the root it commits to, starting from a leaf hash.
+#[pyfunction]
+fn compute_root(leaf: &str, proof: Vec<String>) -> PyResult<String> {
+    let leaf = Felt::from_hex(leaf).map_err(|_| PyValueError::new_err("Invalid leaf"))?;
+    let proof = parse_proof(&proof).map_err(PyValueError::new_err)?;
+    Ok(format!(
+        "{:#x}",
+        crate::core::proof::compute_root(leaf, &proof)
+    ))
+}
+
+/// Checks that an allocation and its proof resolve to a root.
+#[pyfunction(name = "verify_proof")]
+fn verify(root: &str, allocation: PyAllocation, proof: Vec<String>) -> PyResult<bool> {
+    let root = Felt::from_hex(root).map_err(|_| PyValueError::new_err("Invalid root"))?;
+    let leaf = allocation
+        .inner
+        .leaf_hash()
+        .map_err(PyValueError::new_err)?;
+    let proof = parse_proof(&proof).map_err(PyValueError::new_err)?;
+    Ok(verify_proof(root, leaf, &proof))
+}
+
+#[pymodule]
+fn merkle_tree(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAllocation>()?;
+    m.add_class::<PyMerkleTree>()?;
+    m.add_function(wrap_pyfunction!(compute_root, m)?)?;
+    m.add_function(wrap_pyfunction!(verify, m)?)?;
+    Ok(())
+}
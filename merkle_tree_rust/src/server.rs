@@ -0,0 +1,192 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use crate::core::proof::{parse_proof, verify_proof};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::net::SocketAddr;
+use std::path::Path;
+use tiny_http::{Header, Request, Response, Server};
+
+/// Largest request body accepted by `POST /verify`.
+const MAX_BODY_SIZE: u64 = 1024 * 1024;
+
+/// Serves roots and proofs for a set of campaigns, each backed by a `MerkleTree`.
+pub struct ProofServer {
+    campaigns: BTreeMap<String, MerkleTree>,
+}
+
+/// A `ProofServer` bound to a socket, ready to accept requests.
+pub struct BoundProofServer {
+    server: Server,
+    proofs: ProofServer,
+}
+
+#[derive(Deserialize)]
+struct VerifyRequest {
+    campaign: String,
+    allocation: Allocation,
+    proof: Vec<String>,
+}
+
+impl ProofServer {
+    /// Creates a server from campaign names mapped to their trees.
+    pub fn new(campaigns: BTreeMap<String, MerkleTree>) -> Self {
+        ProofServer { campaigns }
+    }
+
+    /// Loads every serialized `MerkleTree` in a directory, named after its file stem.
+    pub fn load_dir(path: &Path) -> Result<Self, String> {
+        let entries =
+            fs::read_dir(path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+
+        let mut campaigns = BTreeMap::new();
+        for entry in entries {
+            let path = entry.map_err(|e| e.to_string())?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+
+            let campaign = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .ok_or(format!("Invalid campaign file name: {:?}", path))?
+                .to_string();
+            let data = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+            let tree: MerkleTree = serde_json::from_str(&data)
+                .map_err(|e| format!("Failed to load campaign {}: {}", campaign, e))?;
+
+            campaigns.insert(campaign, tree);
+        }
+
+        Ok(ProofServer::new(campaigns))
+    }
+
+    /// Routes a request and returns the status code and JSON body to answer with.
+    pub fn handle(&self, method: &str, url: &str, body: &str) -> (u16, Value) {
+        let path = url.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        match (method, segments.as_slice()) {
+            ("GET", ["roots"]) => (200, self.roots()),
+            ("GET", ["proof", campaign, address]) => self.proof(campaign, address),
+            ("POST", ["verify"]) => self.verify(body),
+            (_, ["roots"]) | (_, ["proof", _, _]) | (_, ["verify"]) => {
+                error(405, "Method not allowed")
+            }
+            _ => error(404, "Not found"),
+        }
+    }
+
+    /// Binds the server to an address such as `127.0.0.1:8080`.
+    pub fn bind(self, addr: &str) -> Result<BoundProofServer, String> {
+        let server = Server::http(addr).map_err(|e| format!("Failed to bind {}: {}", addr, e))?;
+        Ok(BoundProofServer {
+            server,
+            proofs: self,
+        })
+    }
+
+    fn roots(&self) -> Value {
+        let roots: BTreeMap<&str, String> = self
+            .campaigns
+            .iter()
+            .map(|(name, tree)| (name.as_str(), format!("{:#x}", tree.root.value)))
+            .collect();
+        json!({ "roots": roots })
+    }
+
+    fn proof(&self, campaign: &str, address: &str) -> (u16, Value) {
+        let Some(tree) = self.campaigns.get(campaign) else {
+            return error(404, "Unknown campaign");
+        };
+
+        let proofs = match tree.proofs_for(address) {
+            Ok(proofs) => proofs,
+            Err(e) => return error(400, &e),
+        };
+        if proofs.is_empty() {
+            return error(404, "No allocation for address");
+        }
+
+        let allocations = tree.allocations_for(address);
+        let proofs: Vec<Value> = allocations
+            .into_iter()
+            .zip(proofs)
+            .map(|(allocation, calldata)| json!({ "allocation": allocation, "calldata": calldata }))
+            .collect();
+
+        (
+            200,
+            json!({
+                "campaign": campaign,
+                "root": format!("{:#x}", tree.root.value),
+                "proofs": proofs,
+            }),
+        )
+    }
+
+    fn verify(&self, body: &str) -> (u16, Value) {
+        let request: VerifyRequest = match serde_json::from_str(body) {
+            Ok(request) => request,
+            Err(e) => return error(400, &format!("Invalid request body: {}", e)),
+        };
+        let Some(tree) = self.campaigns.get(&request.campaign) else {
+            return error(404, "Unknown campaign");
+        };
+
+        let leaf = match request.allocation.leaf_hash() {
+            Ok(leaf) => leaf,
+            Err(e) => return error(400, &e),
+        };
+        let proof = match parse_proof(&request.proof) {
+            Ok(proof) => proof,
+            Err(e) => return error(400, &e),
+        };
+
+        let valid = verify_proof(tree.root.value, leaf, &proof);
+        (200, json!({ "valid": valid }))
+    }
+}
+
+impl BoundProofServer {
+    /// Returns the socket address the server listens on.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Answers requests until the process exits.
+    pub fn run(self) {
+        for request in self.server.incoming_requests() {
+            respond(&self.proofs, request);
+        }
+    }
+}
+
+fn respond(proofs: &ProofServer, mut request: Request) {
+    let mut body = String::new();
+    let (status, value) = match request
+        .as_reader()
+        .take(MAX_BODY_SIZE + 1)
+        .read_to_string(&mut body)
+    {
+        Ok(size) if size as u64 > MAX_BODY_SIZE => error(413, "Request body too large"),
+        Ok(_) => proofs.handle(&request.method().to_string(), request.url(), &body),
+        Err(_) => error(400, "Request body is not valid UTF-8"),
+    };
+
+    let header = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+    let response = Response::from_string(value.to_string())
+        .with_status_code(status)
+        .with_header(header);
+
+    // The client may have hung up; there is nobody left to report the failure to.
+    let _ = request.respond(response);
+}
+
+fn error(status: u16, message: &str) -> (u16, Value) {
+    (status, json!({ "error": message }))
+}
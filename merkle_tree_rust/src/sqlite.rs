@@ -0,0 +1,265 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use crate::core::proof::build_leaf_levels;
+use rusqlite::{params, Connection, OptionalExtension};
+use starknet::core::types::Felt;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS campaigns (
+    id TEXT PRIMARY KEY,
+    root TEXT NOT NULL,
+    leaf_count INTEGER NOT NULL,
+    level_count INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS allocations (
+    campaign TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    address TEXT NOT NULL,
+    address_key TEXT NOT NULL,
+    amount TEXT NOT NULL,
+    timestamp TEXT NOT NULL,
+    id TEXT NOT NULL,
+    leaf_hash TEXT NOT NULL,
+    PRIMARY KEY (campaign, position)
+);
+CREATE INDEX IF NOT EXISTS allocations_by_address ON allocations (campaign, address_key);
+CREATE INDEX IF NOT EXISTS allocations_by_leaf ON allocations (campaign, leaf_hash);
+CREATE TABLE IF NOT EXISTS level_hashes (
+    campaign TEXT NOT NULL REFERENCES campaigns(id) ON DELETE CASCADE,
+    level INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    hash TEXT NOT NULL,
+    PRIMARY KEY (campaign, level, position)
+);
+";
+
+/// Persists built trees in a SQLite database so proofs can be served without rebuilding them.
+///
+/// Amounts and ids are stored as decimal text and hashes as hex, so every `u64` round-trips.
+pub struct ProofStore {
+    conn: Connection,
+}
+
+impl ProofStore {
+    /// Opens or creates a database file.
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let conn =
+            Connection::open(path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+        ProofStore::init(conn)
+    }
+
+    /// Opens a database that lives only as long as the store.
+    pub fn open_in_memory() -> Result<Self, String> {
+        ProofStore::init(Connection::open_in_memory().map_err(|e| e.to_string())?)
+    }
+
+    fn init(conn: Connection) -> Result<Self, String> {
+        conn.execute_batch("PRAGMA foreign_keys = ON;")
+            .and_then(|_| conn.execute_batch(SCHEMA))
+            .map_err(|e| format!("Failed to create schema: {}", e))?;
+        Ok(ProofStore { conn })
+    }
+
+    /// Writes the allocations, leaves, level hashes and root of a tree, replacing the campaign.
+    pub fn store_tree(&mut self, campaign: &str, tree: &MerkleTree) -> Result<(), String> {
+        let allocations = tree.get_allocations();
+        let leaves = allocations
+            .iter()
+            .map(Allocation::leaf_hash)
+            .collect::<Result<Vec<_>, _>>()?;
+        let levels = build_leaf_levels(leaves);
+        if levels.last().unwrap()[0] != tree.root.value {
+            return Err("Root does not match allocations".to_string());
+        }
+
+        let tx = self.conn.transaction().map_err(|e| e.to_string())?;
+        tx.execute("DELETE FROM campaigns WHERE id = ?1", params![campaign])
+            .map_err(|e| e.to_string())?;
+        tx.execute(
+            "INSERT INTO campaigns (id, root, leaf_count, level_count) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                campaign,
+                format!("{:#x}", tree.root.value),
+                allocations.len(),
+                levels.len()
+            ],
+        )
+        .map_err(|e| e.to_string())?;
+
+        {
+            let mut insert_allocation = tx
+                .prepare(
+                    "INSERT INTO allocations
+                     (campaign, position, address, address_key, amount, timestamp, id, leaf_hash)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                )
+                .map_err(|e| e.to_string())?;
+            for (position, (allocation, leaf)) in
+                allocations.iter().zip(levels[0].iter()).enumerate()
+            {
+                let (address, _, _, _) = allocation.to_felts()?;
+                insert_allocation
+                    .execute(params![
+                        campaign,
+                        position,
+                        allocation.address,
+                        format!("{:#x}", address),
+                        allocation.amount.to_string(),
+                        allocation.timestamp,
+                        allocation.id.to_string(),
+                        format!("{:#x}", leaf)
+                    ])
+                    .map_err(|e| e.to_string())?;
+            }
+
+            let mut insert_hash = tx
+                .prepare(
+                    "INSERT INTO level_hashes (campaign, level, position, hash)
+                     VALUES (?1, ?2, ?3, ?4)",
+                )
+                .map_err(|e| e.to_string())?;
+            for (level, hashes) in levels.iter().enumerate() {
+                for (position, hash) in hashes.iter().enumerate() {
+                    insert_hash
+                        .execute(params![campaign, level, position, format!("{:#x}", hash)])
+                        .map_err(|e| e.to_string())?;
+                }
+            }
+        }
+
+        tx.commit().map_err(|e| e.to_string())
+    }
+
+    /// Returns the root of every stored campaign.
+    pub fn roots(&self) -> Result<BTreeMap<String, String>, String> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT id, root FROM campaigns")
+            .map_err(|e| e.to_string())?;
+        let rows = statement
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| e.to_string())?;
+        rows.collect::<Result<_, _>>().map_err(|e| e.to_string())
+    }
+
+    /// Returns the root of a campaign, if it is stored.
+    pub fn root(&self, campaign: &str) -> Result<Option<String>, String> {
+        self.conn
+            .query_row(
+                "SELECT root FROM campaigns WHERE id = ?1",
+                params![campaign],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())
+    }
+
+    /// Returns every allocation of an address in a campaign, in tree order.
+    pub fn allocations_for(
+        &self,
+        campaign: &str,
+        address: &str,
+    ) -> Result<Vec<Allocation>, String> {
+        let address = Felt::from_hex(address).map_err(|_| "Invalid address")?;
+        let mut statement = self
+            .conn
+            .prepare(
+                "SELECT address, amount, timestamp, id FROM allocations
+                 WHERE campaign = ?1 AND address_key = ?2 ORDER BY position",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = statement
+            .query_map(params![campaign, format!("{:#x}", address)], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.map(|row| {
+            let (address, amount, timestamp, id) = row.map_err(|e| e.to_string())?;
+            Ok(Allocation {
+                address,
+                amount: amount.parse().map_err(|_| "Invalid stored amount")?,
+                timestamp,
+                id: id.parse().map_err(|_| "Invalid stored id")?,
+            })
+        })
+        .collect()
+    }
+
+    /// Reads the sibling hashes of an allocation's leaf up to the stored root.
+    pub fn proof(&self, campaign: &str, allocation: &Allocation) -> Result<Vec<Felt>, String> {
+        let level_count: usize = self
+            .conn
+            .query_row(
+                "SELECT level_count FROM campaigns WHERE id = ?1",
+                params![campaign],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?
+            .ok_or(format!("Campaign {} not found", campaign))?;
+
+        let leaf = format!("{:#x}", allocation.leaf_hash()?);
+        let mut position: usize = self
+            .conn
+            .query_row(
+                "SELECT position FROM allocations
+                 WHERE campaign = ?1 AND leaf_hash = ?2 ORDER BY position LIMIT 1",
+                params![campaign, leaf],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?
+            .ok_or("Allocation not found")?;
+
+        let mut statement = self
+            .conn
+            .prepare(
+                "SELECT position, hash FROM level_hashes
+                 WHERE campaign = ?1 AND level = ?2 AND position IN (?3, ?4)",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let mut proof = vec![];
+        for level in 0..level_count - 1 {
+            let rows = statement
+                .query_map(params![campaign, level, position, position ^ 1], |row| {
+                    Ok((row.get::<_, usize>(0)?, row.get::<_, String>(1)?))
+                })
+                .map_err(|e| e.to_string())?
+                .collect::<Result<BTreeMap<_, _>, _>>()
+                .map_err(|e| e.to_string())?;
+
+            // The last node of an odd level is paired with itself
+            let sibling = rows
+                .get(&(position ^ 1))
+                .or(rows.get(&position))
+                .ok_or(format!("Missing hash at level {}", level))?;
+            proof.push(Felt::from_hex(sibling).map_err(|_| "Invalid stored hash")?);
+            position /= 2;
+        }
+        Ok(proof)
+    }
+
+    /// Builds `[address, amount, timestamp, id, ...proof]` calldata for every allocation of an
+    /// address, as `MerkleTree::proofs_for` does.
+    pub fn proofs_for(&self, campaign: &str, address: &str) -> Result<Vec<Vec<String>>, String> {
+        self.allocations_for(campaign, address)?
+            .iter()
+            .map(|allocation| {
+                let proof = self.proof(campaign, allocation)?;
+                let felts = allocation.to_felts()?;
+                let mut calldata = vec![felts.0, felts.1, felts.2, felts.3];
+                calldata.extend(proof);
+                Ok(calldata.iter().map(|f| format!("{:#x}", f)).collect())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,145 @@
+use crate::core::allocation::Allocation;
+use crate::core::merkle_tree::MerkleTree;
+use crate::core::proof::{parse_proof, verify_proof};
+use serde::Serialize;
+use starknet::core::types::Felt;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &str = r#"
+export interface Allocation {
+    address: string;
+    amount: number | bigint;
+    timestamp: string;
+    id: number | bigint;
+}
+
+export interface AddressProof {
+    allocation: Allocation;
+    calldata: string[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "Allocation")]
+    pub type JsAllocation;
+
+    #[wasm_bindgen(typescript_type = "Allocation[]")]
+    pub type JsAllocationArray;
+
+    #[wasm_bindgen(typescript_type = "AddressProof[]")]
+    pub type JsAddressProofArray;
+
+    #[wasm_bindgen(typescript_type = "string[]")]
+    pub type JsStringArray;
+}
+
+/// Proof of one allocation, in the shape described by the `AddressProof` type.
+#[derive(Serialize)]
+struct AddressProof<'a> {
+    allocation: &'a Allocation,
+    calldata: Vec<String>,
+}
+
+/// JavaScript handle on a `MerkleTree`.
+#[wasm_bindgen(js_name = MerkleTree)]
+pub struct WasmMerkleTree {
+    tree: MerkleTree,
+}
+
+#[wasm_bindgen(js_class = MerkleTree)]
+impl WasmMerkleTree {
+    /// Builds a tree from a list of allocations, rejecting invalid ones instead of panicking.
+    #[wasm_bindgen(constructor)]
+    pub fn new(allocations: JsAllocationArray) -> Result<WasmMerkleTree, JsError> {
+        let allocations: Vec<Allocation> = from_js(allocations.into())?;
+        if allocations.is_empty() {
+            return Err(JsError::new("Tree has no allocations"));
+        }
+        for allocation in allocations.iter() {
+            allocation.to_felts().map_err(|e| JsError::new(&e))?;
+        }
+
+        Ok(WasmMerkleTree {
+            tree: MerkleTree::new(allocations),
+        })
+    }
+
+    /// Root of the tree as a hex string, as passed to `set_merkle_root`.
+    #[wasm_bindgen(getter)]
+    pub fn root(&self) -> String {
+        format!("{:#x}", self.tree.root.value)
+    }
+
+    /// Generates `[address, amount, timestamp, id, ...proof]` calldata for an allocation.
+    #[wasm_bindgen(js_name = buildAddressCalldata)]
+    pub fn build_address_calldata(
+        &self,
+        address: &str,
+        amount: u64,
+        timestamp: &str,
+        id: u64,
+    ) -> Result<JsStringArray, JsError> {
+        let calldata = self
+            .tree
+            .build_address_calldata(address, amount, timestamp, id)
+            .map_err(|e| JsError::new(&e))?;
+        Ok(to_js(&calldata)?.unchecked_into())
+    }
+
+    /// Returns every allocation held by an address with its calldata.
+    #[wasm_bindgen(js_name = proofsFor)]
+    pub fn proofs_for(&self, address: &str) -> Result<JsAddressProofArray, JsError> {
+        let proofs = self
+            .tree
+            .proofs_for(address)
+            .map_err(|e| JsError::new(&e))?;
+        let proofs: Vec<AddressProof> = self
+            .tree
+            .allocations_for(address)
+            .into_iter()
+            .zip(proofs)
+            .map(|(allocation, calldata)| AddressProof {
+                allocation,
+                calldata,
+            })
+            .collect();
+        Ok(to_js(&proofs)?.unchecked_into())
+    }
+}
+
+/// Computes the leaf hash the `Claimer` contract checks for an allocation.
+#[wasm_bindgen(js_name = leafHash)]
+pub fn leaf_hash(allocation: JsAllocation) -> Result<String, JsError> {
+    let allocation: Allocation = from_js(allocation.into())?;
+    let leaf = allocation.leaf_hash().map_err(|e| JsError::new(&e))?;
+    Ok(format!("{:#x}", leaf))
+}
+
+/// Checks a proof against a root without building a tree.
+#[wasm_bindgen(js_name = verifyProof)]
+pub fn verify(root: &str, allocation: JsAllocation, proof: JsStringArray) -> Result<bool, JsError> {
+    let root = Felt::from_hex(root).map_err(|_| JsError::new("Invalid root"))?;
+    let allocation: Allocation = from_js(allocation.into())?;
+    let proof: Vec<String> = from_js(proof.into())?;
+
+    let leaf = allocation.leaf_hash().map_err(|e| JsError::new(&e))?;
+    let proof = parse_proof(&proof).map_err(|e| JsError::new(&e))?;
+    Ok(verify_proof(root, leaf, &proof))
+}
+
+fn from_js<T: serde::de::DeserializeOwned>(value: JsValue) -> Result<T, JsError> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Serializes for JavaScript, with `u64` amounts and ids as `bigint` so none lose precision.
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    value
+        .serialize(
+            &serde_wasm_bindgen::Serializer::json_compatible()
+                .serialize_large_number_types_as_bigints(true),
+        )
+        .map_err(|e| JsError::new(&e.to_string()))
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use common::allocation;
+use merkle_tree::core::aggregate::{aggregate, AggregationKey};
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+
+fn sample_rows() -> Vec<Allocation> {
+    vec![
+        allocation("0xabc", 100, "0x2", 0),
+        allocation("0x123", 50, "0x1", 0),
+        allocation("0xabc", 25, "0x3", 0),
+        allocation("0x123", 75, "0x1", 0),
+        allocation("0xabc", 10, "0x2", 0),
+    ]
+}
+
+#[test]
+fn test_aggregate_by_address() {
+    let report = aggregate(&sample_rows(), AggregationKey::Address, None).unwrap();
+
+    assert_eq!(report.key, AggregationKey::Address);
+    assert_eq!(report.row_count, 5);
+    assert_eq!(
+        report.allocations(),
+        vec![
+            Allocation {
+                address: "0x123".to_string(),
+                amount: 125,
+                timestamp: "0x1".to_string(),
+                id: 1,
+            },
+            Allocation {
+                address: "0xabc".to_string(),
+                amount: 135,
+                timestamp: "0x3".to_string(),
+                id: 1,
+            },
+        ]
+    );
+    assert_eq!(report.leaves[0].rows, vec![1, 3]);
+    assert_eq!(report.leaves[1].rows, vec![0, 2, 4]);
+}
+
+#[test]
+fn test_aggregate_by_address_and_timestamp() {
+    let report = aggregate(&sample_rows(), AggregationKey::AddressTimestamp, None).unwrap();
+
+    let leaves: Vec<(String, u64, String, u64)> = report
+        .allocations()
+        .into_iter()
+        .map(|a| (a.address, a.amount, a.timestamp, a.id))
+        .collect();
+    assert_eq!(
+        leaves,
+        vec![
+            ("0x123".to_string(), 125, "0x1".to_string(), 1),
+            ("0xabc".to_string(), 110, "0x2".to_string(), 1),
+            ("0xabc".to_string(), 25, "0x3".to_string(), 2),
+        ]
+    );
+    assert_eq!(report.leaves[1].rows, vec![0, 4]);
+}
+
+#[test]
+fn test_leaf_for_row() {
+    let report = aggregate(&sample_rows(), AggregationKey::AddressTimestamp, None).unwrap();
+
+    assert_eq!(report.leaf_for_row(2).unwrap().allocation.timestamp, "0x3");
+    assert_eq!(report.leaf_for_row(3).unwrap().allocation.address, "0x123");
+    assert_eq!(report.leaf_for_row(4).unwrap().allocation.amount, 110);
+    assert!(report.leaf_for_row(5).is_none());
+}
+
+#[test]
+fn test_aggregate_continues_previous_ids() {
+    let previous = MerkleTree::new(vec![
+        allocation("0x123", 1, "0x0", 1),
+        allocation("0xabc", 1, "0x0", 4),
+    ]);
+    let report = aggregate(
+        &sample_rows(),
+        AggregationKey::AddressTimestamp,
+        Some(&previous),
+    )
+    .unwrap();
+
+    let ids: Vec<u64> = report.allocations().iter().map(|a| a.id).collect();
+    assert_eq!(ids, vec![2, 5, 6]);
+}
+
+#[test]
+fn test_aggregate_ignores_row_order() {
+    let mut reordered = sample_rows();
+    reordered.reverse();
+
+    let report = aggregate(&sample_rows(), AggregationKey::Address, None).unwrap();
+    let other = aggregate(&reordered, AggregationKey::Address, None).unwrap();
+    assert_eq!(report.allocations(), other.allocations());
+    assert_eq!(
+        MerkleTree::new(report.allocations()).root.value,
+        MerkleTree::new(other.allocations()).root.value
+    );
+}
+
+#[test]
+fn test_aggregate_merges_respelled_addresses() {
+    let rows = vec![
+        allocation("0x00ABC", 1, "0x02", 0),
+        allocation("0xabc", 2, "0x2", 0),
+        allocation("0xAbC", 3, "0x2", 0),
+    ];
+
+    let report = aggregate(&rows, AggregationKey::AddressTimestamp, None).unwrap();
+    assert_eq!(report.leaves.len(), 1);
+    assert_eq!(report.leaves[0].allocation.address, "0xabc");
+    assert_eq!(report.leaves[0].allocation.amount, 6);
+    assert_eq!(report.leaves[0].rows, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_aggregate_reports_overflow() {
+    let rows = vec![
+        allocation("0xabc", u64::MAX, "0x1", 0),
+        allocation("0xabc", 1, "0x2", 0),
+    ];
+
+    assert_eq!(
+        aggregate(&rows, AggregationKey::Address, None)
+            .err()
+            .unwrap(),
+        "Amount overflow for address 0xabc"
+    );
+    assert!(aggregate(&rows, AggregationKey::AddressTimestamp, None).is_ok());
+}
+
+#[test]
+fn test_aggregate_reports_invalid_rows() {
+    let mut rows = sample_rows();
+    rows[3].address = "not hex".to_string();
+
+    assert_eq!(
+        aggregate(&rows, AggregationKey::Address, None)
+            .err()
+            .unwrap(),
+        "Invalid row 3: Invalid address"
+    );
+}
+
+#[test]
+fn test_report_json() {
+    let report = aggregate(&sample_rows(), AggregationKey::AddressTimestamp, None).unwrap();
+    let json = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(json["key"], "address_timestamp");
+    assert_eq!(json["row_count"], 5);
+    assert_eq!(json["leaves"][0]["rows"], serde_json::json!([1, 3]));
+    assert_eq!(json["row_leaves"], serde_json::json!([1, 0, 2, 0, 1]));
+    assert_eq!(
+        serde_json::from_value::<merkle_tree::core::aggregate::AggregationReport>(json).unwrap(),
+        report
+    );
+}
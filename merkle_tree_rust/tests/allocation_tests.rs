@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::legacy_numeric_constants)]
 mod tests {
     use std::{
         hash::{DefaultHasher, Hash, Hasher},
@@ -99,4 +100,21 @@ mod tests {
 
         assert_eq!(hash, other_hash, "Hash should be the same");
     }
+
+    #[test]
+    fn test_allocation_leaf_hash() {
+        let allocation = Allocation {
+            address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            amount: 150,
+            timestamp: "0x2".to_string(),
+            id: 1,
+        };
+
+        // Same hash as the first sibling in the second proof of output/first_merkle_tree_data.txt
+        assert_eq!(
+            allocation.leaf_hash().unwrap(),
+            Felt::from_hex("0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58")
+                .unwrap()
+        );
+    }
 }
@@ -0,0 +1,178 @@
+use merkle_tree::core::attestation::{attestation_hash, RootAttestation};
+use starknet::core::types::Felt;
+use starknet_crypto::get_public_key;
+
+const PRIVATE_KEY: &str = "0x1234567890987654321";
+const FIRST_WAVE_ROOT: &str =
+    "803781063426407299979325390167664109772842041387232186868510660774343066272";
+const SECOND_WAVE_ROOT: &str =
+    "3023878233865233747692111000084174893656568287435392306059398425498163029420";
+
+fn sample_attestation() -> RootAttestation {
+    RootAttestation::sign(
+        &Felt::from_hex(PRIVATE_KEY).unwrap(),
+        Felt::from_bytes_be_slice(b"carbonable"),
+        1,
+        Felt::from_dec_str(FIRST_WAVE_ROOT).unwrap(),
+        Felt::from_hex("0xabc").unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_signature_matches_cairo() {
+    // Same vector as merkle_tree_cairo/tests/tests_attestation.cairo
+    let attestation = sample_attestation();
+
+    assert_eq!(
+        attestation.message_hash(),
+        Felt::from_hex("0x3bf12be181772522b3d642c4ed6aaa3d6272837ee8c585a1a295f84841d3639")
+            .unwrap()
+    );
+    assert_eq!(
+        attestation.signer,
+        Felt::from_hex("0x20c29f1c98f3320d56f01c13372c923123c35828bce54f2153aa1cfe61c44f2")
+            .unwrap()
+    );
+    assert_eq!(
+        attestation.signature_r,
+        Felt::from_hex("0x267f8951337353faf404189d20411e2c49ca6cdd4655640c5198d63a461f03c")
+            .unwrap()
+    );
+    assert_eq!(
+        attestation.signature_s,
+        Felt::from_hex("0x5e483b241e708d71f005cd75de891167dccba0844a075e204b9559bda854fef")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_second_wave_signature_matches_cairo() {
+    // Same vector as the second wave in merkle_tree_cairo/tests/tests_attestation.cairo
+    let attestation = RootAttestation::sign(
+        &Felt::from_hex(PRIVATE_KEY).unwrap(),
+        Felt::from_bytes_be_slice(b"carbonable"),
+        2,
+        Felt::from_dec_str(SECOND_WAVE_ROOT).unwrap(),
+        Felt::from_hex("0xabc").unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        attestation.signature_r,
+        Felt::from_hex("0x4d5a8564df35a56c970fc21bf3aabe3b38fd2e7bb524bad5828889cd09b0ece")
+            .unwrap()
+    );
+    assert_eq!(
+        attestation.signature_s,
+        Felt::from_hex("0x30b51c075f8d47298ef9661b6539100aa89718fbd82d9489c8875ae7ae22cd4")
+            .unwrap()
+    );
+    assert!(attestation.verify().is_ok());
+}
+
+#[test]
+fn test_wave_zero_signature_matches_cairo() {
+    // Same vector as the wave 0 attestation in merkle_tree_cairo/tests/tests_attestation.cairo
+    let attestation = RootAttestation::sign(
+        &Felt::from_hex(PRIVATE_KEY).unwrap(),
+        Felt::from_bytes_be_slice(b"carbonable"),
+        0,
+        Felt::from_dec_str(FIRST_WAVE_ROOT).unwrap(),
+        Felt::from_hex("0xabc").unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        attestation.signature_r,
+        Felt::from_hex("0x37cba89bc68de4a12342f1537349772f1934d8d8e514e7a5901d621b68b3d0a")
+            .unwrap()
+    );
+    assert_eq!(
+        attestation.signature_s,
+        Felt::from_hex("0xe50b47bb308d30df3a71a4efc52444a963208b2b706da69fdfef73f44ec400").unwrap()
+    );
+    assert!(attestation.verify().is_ok());
+}
+
+#[test]
+fn test_signed_attestation_verifies() {
+    let attestation = sample_attestation();
+    let signer = get_public_key(&Felt::from_hex(PRIVATE_KEY).unwrap());
+
+    assert!(attestation.verify().is_ok());
+    assert!(attestation.verify_signer(&signer).is_ok());
+    assert_eq!(attestation, sample_attestation());
+}
+
+#[test]
+fn test_message_hash_covers_every_field() {
+    let attestation = sample_attestation();
+    let hash = attestation.message_hash();
+
+    assert_eq!(
+        hash,
+        attestation_hash(
+            attestation.campaign_id,
+            attestation.wave,
+            attestation.root,
+            attestation.input_digest
+        )
+    );
+    assert_ne!(
+        hash,
+        attestation_hash(
+            attestation.campaign_id,
+            2,
+            attestation.root,
+            attestation.input_digest
+        )
+    );
+    assert_ne!(
+        hash,
+        attestation_hash(
+            attestation.campaign_id,
+            attestation.wave,
+            attestation.root,
+            Felt::ZERO
+        )
+    );
+}
+
+#[test]
+fn test_tampered_attestation_fails() {
+    let mut root = sample_attestation();
+    root.root = Felt::from_hex("0x1").unwrap();
+    assert_eq!(root.verify().err().unwrap(), "Invalid signature");
+
+    let mut wave = sample_attestation();
+    wave.wave = 2;
+    assert!(wave.verify().is_err());
+
+    let mut signer = sample_attestation();
+    signer.signer = get_public_key(&Felt::from_hex("0x42").unwrap());
+    assert!(signer.verify().is_err());
+}
+
+#[test]
+fn test_unexpected_signer_fails() {
+    let attestation = sample_attestation();
+    let other = get_public_key(&Felt::from_hex("0x42").unwrap());
+
+    assert_eq!(
+        attestation.verify_signer(&other).err().unwrap(),
+        format!("Unexpected signer: {:#x}", attestation.signer)
+    );
+}
+
+#[test]
+fn test_attestation_json_round_trips() {
+    let attestation = sample_attestation();
+    let json = serde_json::to_value(&attestation).unwrap();
+
+    assert_eq!(json["campaign_id"], "0x636172626f6e61626c65");
+    assert_eq!(json["wave"], 1);
+    let parsed: RootAttestation = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, attestation);
+    assert!(parsed.verify().is_ok());
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use common::{allocation, load_mock_data};
+use merkle_tree::core::campaign::Campaign;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use std::fs;
+
+#[test]
+fn test_waves_match_merged_trees() {
+    let first_wave = load_mock_data("mock_allocations_first_wave.json");
+    let second_wave = load_mock_data("mock_allocations_second_wave.json");
+
+    let mut campaign = Campaign::new("mock");
+    campaign
+        .add_wave_at("first_wave", first_wave.clone(), 1)
+        .unwrap();
+    campaign
+        .add_wave_at("second_wave", second_wave.clone(), 2)
+        .unwrap();
+
+    let first_tree = MerkleTree::new(first_wave.clone());
+    let merged_tree = first_tree.merge_merkle_trees(second_wave.clone());
+
+    let waves = campaign.waves();
+    assert_eq!(waves.len(), 2);
+    assert_eq!(waves[0].root, format!("{:#x}", first_tree.root.value));
+    assert_eq!(waves[0].leaf_count, first_wave.len());
+    assert_eq!(waves[1].root, format!("{:#x}", merged_tree.root.value));
+    assert_eq!(waves[1].leaf_count, first_wave.len() + second_wave.len());
+    assert_eq!(waves[1].created_at, 2);
+    assert_eq!(campaign.latest().unwrap().label, "second_wave");
+}
+
+#[test]
+fn test_roots_match_published_constants() {
+    let mut campaign = Campaign::new("mock");
+    campaign
+        .add_wave_at(
+            "first_wave",
+            load_mock_data("mock_allocations_first_wave.json"),
+            1,
+        )
+        .unwrap();
+    campaign
+        .add_wave_at(
+            "second_wave",
+            load_mock_data("mock_allocations_second_wave.json"),
+            2,
+        )
+        .unwrap();
+
+    // MERKLE_ROOT_FIRST_WAVE and MERKLE_ROOT_SECOND_WAVE in the Cairo tests
+    assert_eq!(
+        campaign.regenerate_root(0).unwrap().to_string(),
+        "803781063426407299979325390167664109772842041387232186868510660774343066272"
+    );
+    assert_eq!(
+        campaign.regenerate_root(1).unwrap().to_string(),
+        "3023878233865233747692111000084174893656568287435392306059398425498163029420"
+    );
+    assert!(campaign.verify_history().is_ok());
+}
+
+#[test]
+fn test_new_allocations_per_wave() {
+    let shared = allocation("0x1234567890abcdef1234567890abcdef12345678", 150, "0x2", 1);
+    let added = allocation("0x3F5A1E9DAB72F1A8C12D4D9B3A58A7B4425E7B4C", 250, "0x4", 5);
+
+    let mut campaign = Campaign::new("mock");
+    campaign
+        .add_wave_at(
+            "first",
+            vec![
+                shared.clone(),
+                allocation("0xabcdefabcdefabcdefabcdefabcdefabcdef1234", 200, "0x3", 2),
+            ],
+            1,
+        )
+        .unwrap();
+    campaign
+        .add_wave_at("second", vec![shared.clone(), added.clone()], 2)
+        .unwrap();
+
+    assert_eq!(campaign.new_allocations(0).unwrap().len(), 2);
+    assert_eq!(campaign.new_allocations(1).unwrap(), vec![&added]);
+    assert_eq!(
+        campaign.new_allocations(2).unwrap_err(),
+        "Wave 2 does not exist"
+    );
+}
+
+#[test]
+fn test_invalid_waves_are_rejected() {
+    let mut campaign = Campaign::new("mock");
+
+    assert_eq!(
+        campaign.add_wave("empty", vec![]).unwrap_err(),
+        "Wave has no allocations"
+    );
+    assert_eq!(
+        campaign
+            .add_wave(
+                "invalid",
+                vec![allocation("0x1234567dhiodhaoo", 150, "0x2", 1)]
+            )
+            .unwrap_err(),
+        "Invalid address"
+    );
+    assert!(campaign.waves().is_empty());
+}
+
+#[test]
+fn test_save_load_and_detect_tampering() {
+    let mut campaign = Campaign::new("mock");
+    campaign
+        .add_wave(
+            "first_wave",
+            load_mock_data("mock_allocations_second_wave.json"),
+        )
+        .unwrap();
+
+    let path = std::env::temp_dir().join(format!("campaign_{}.json", std::process::id()));
+    campaign.save(&path).unwrap();
+    let loaded = Campaign::load(&path).unwrap();
+    assert_eq!(loaded, campaign);
+
+    // Editing an input after publication no longer reproduces the recorded root
+    let data = fs::read_to_string(&path)
+        .unwrap()
+        .replacen("\"amount\": 500", "\"amount\": 501", 1);
+    fs::write(&path, data).unwrap();
+    let tampered = Campaign::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(tampered
+        .verify_history()
+        .unwrap_err()
+        .starts_with("Wave 0 root mismatch"));
+}
+
+#[test]
+fn test_published_history_is_reproducible() {
+    let campaign = Campaign::load(std::path::Path::new("output/campaign.json"))
+        .expect("Failed to load output/campaign.json");
+
+    assert_eq!(campaign.waves().len(), 2);
+    assert!(campaign.verify_history().is_ok());
+}
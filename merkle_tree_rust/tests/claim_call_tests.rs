@@ -0,0 +1,124 @@
+mod common;
+
+use common::sample_allocations;
+use merkle_tree::core::allocation::u64_to_felt;
+use merkle_tree::core::merkle_tree::{MerkleTree, CLAIM_ENTRYPOINT};
+use merkle_tree::core::proof::parse_proof;
+use starknet::core::types::Felt;
+use std::fs;
+
+const CLAIMER_ADDRESS: &str = "0x49d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7";
+
+/// Extracts the `(name, type)` parameters of `IClaimer::claim` from the Cairo source.
+fn claim_abi_params() -> Vec<(String, String)> {
+    let source = fs::read_to_string("../merkle_tree_cairo/src/claimer.cairo")
+        .expect("Failed to read claimer.cairo");
+    let trait_start = source
+        .find("pub trait IClaimer")
+        .expect("IClaimer not found");
+    let signature_start = trait_start
+        + source[trait_start..]
+            .find(&format!("fn {}(", CLAIM_ENTRYPOINT))
+            .expect("claim not found in IClaimer");
+    let params_start = signature_start + source[signature_start..].find('(').unwrap() + 1;
+    let params_end = signature_start + source[signature_start..].find(");").unwrap();
+
+    source[params_start..params_end]
+        .split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty() && !param.starts_with("ref self"))
+        .map(|param| {
+            let (name, ty) = param.split_once(':').unwrap();
+            (name.trim().to_string(), ty.trim().to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn test_claim_abi_matches_calldata_layout() {
+    // The call builder serializes exactly these arguments, in this order
+    let params = claim_abi_params();
+    let expected = [
+        ("amount", "u128"),
+        ("timestamp", "u128"),
+        ("id", "u128"),
+        ("proof", "Array::<felt252>"),
+    ];
+
+    assert_eq!(params.len(), expected.len());
+    for ((name, ty), (expected_name, expected_ty)) in params.iter().zip(expected) {
+        assert_eq!(name, expected_name);
+        assert_eq!(ty, expected_ty);
+    }
+}
+
+#[test]
+fn test_build_claim_call() {
+    let allocations = sample_allocations();
+    let tree = MerkleTree::new(allocations.clone());
+    let allocation = &allocations[1];
+
+    let call = tree
+        .build_claim_call(
+            CLAIMER_ADDRESS,
+            &allocation.address,
+            allocation.amount,
+            &allocation.timestamp,
+            allocation.id,
+        )
+        .expect("Failed to build claim call");
+
+    assert_eq!(call.to, Felt::from_hex(CLAIMER_ADDRESS).unwrap());
+    // starknet_keccak("claim")
+    assert_eq!(
+        call.selector,
+        Felt::from_hex("0xb758361d5e84380ef1e632f89d8e76a8677dbc3f4b93a4f9d75d2a6048f312").unwrap()
+    );
+
+    let calldata = tree
+        .build_address_calldata(
+            &allocation.address,
+            allocation.amount,
+            &allocation.timestamp,
+            allocation.id,
+        )
+        .unwrap();
+    let proof = parse_proof(&calldata[4..]).unwrap();
+
+    let mut expected = vec![
+        u64_to_felt(200),
+        Felt::from_hex("0x3").unwrap(),
+        u64_to_felt(2),
+        Felt::from(proof.len()),
+    ];
+    expected.extend(proof);
+    assert_eq!(call.calldata, expected);
+}
+
+#[test]
+fn test_build_claim_call_errors() {
+    let allocations = sample_allocations();
+    let tree = MerkleTree::new(allocations.clone());
+    let allocation = &allocations[0];
+
+    let result = tree.build_claim_call(
+        "0xnothex",
+        &allocation.address,
+        allocation.amount,
+        &allocation.timestamp,
+        allocation.id,
+    );
+    assert_eq!(result.unwrap_err(), "Invalid contract address");
+
+    let result = tree.build_claim_call(CLAIMER_ADDRESS, &allocation.address, 999, "0x2", 1);
+    assert_eq!(result.unwrap_err(), "Allocation not found");
+
+    let result = tree.build_claim_call(
+        CLAIMER_ADDRESS,
+        &allocation.address,
+        allocation.amount,
+        "0x100000000000000000000000000000000",
+        allocation.id,
+    );
+    assert_eq!(result.unwrap_err(), "Timestamp does not fit in u128");
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use common::{allocation, load_mock_data};
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::claims::{ClaimTracker, ClaimedEvent};
+use merkle_tree::core::merkle_tree::MerkleTree;
+use std::path::Path;
+
+fn event(claimee: &str, amount: u64, timestamp: &str) -> ClaimedEvent {
+    ClaimedEvent {
+        claimee: claimee.to_string(),
+        amount,
+        timestamp: timestamp.to_string(),
+        transaction_hash: None,
+    }
+}
+
+#[test]
+fn test_ingest_indexer_dump() {
+    let allocations = load_mock_data("mock_allocations_first_wave.json");
+    let tree = MerkleTree::new(allocations.clone());
+    let events = ClaimTracker::load_events(Path::new("data/mock_claimed_events.json"))
+        .expect("Failed to load events");
+
+    let mut tracker = ClaimTracker::new(&tree);
+    tracker.ingest(events.clone());
+
+    // Bob's first claim, the upper-case address and the second allocation of 0xabcd...1234
+    assert_eq!(tracker.claimed_allocations().len(), 3);
+    assert!(tracker.is_claimed(0));
+    assert!(tracker.is_claimed(1));
+    assert!(!tracker.is_claimed(2));
+
+    // Bob claiming the same allocation twice and an unknown claimee
+    assert_eq!(tracker.unmatched(), &events[3..]);
+    assert_eq!(tracker.unclaimed_allocations().len(), allocations.len() - 3);
+}
+
+#[test]
+fn test_unclaimed_totals_per_address() {
+    let allocations = load_mock_data("mock_allocations_first_wave.json");
+    let tree = MerkleTree::new(allocations.clone());
+
+    let mut tracker = ClaimTracker::new(&tree);
+    tracker.ingest(vec![
+        event("0xabcdefabcdefabcdefabcdefabcdefabcdef1234", 200, "0x1"),
+        event("0xabcdefabcdefabcdefabcdefabcdefabcdef1234", 1000, "0x15"),
+    ]);
+
+    let totals = tracker.unclaimed_totals();
+    // 1100 + 2900 left out of 200 + 1100 + 1000 + 2900
+    assert_eq!(totals["0xabcdefabcdefabcdefabcdefabcdefabcdef1234"], 4000);
+    assert_eq!(totals["0x1234567890abcdef1234567890abcdef12345678"], 300);
+
+    let total: u128 = allocations.iter().map(|a| a.amount as u128).sum();
+    let report = tracker.report();
+    assert_eq!(report.claimed, 2);
+    assert_eq!(report.unclaimed, allocations.len() - 2);
+    assert_eq!(report.unclaimed_total, total - 1200);
+    assert!(report.unmatched_events.is_empty());
+}
+
+#[test]
+fn test_unclaimed_totals_above_u64() {
+    let tree = MerkleTree::new(vec![
+        allocation("0xabc", u64::MAX, "0x1", 1),
+        allocation("0xabc", u64::MAX, "0x2", 2),
+    ]);
+
+    let report = ClaimTracker::new(&tree).report();
+    assert_eq!(report.unclaimed_totals["0xabc"], 2 * u64::MAX as u128);
+    assert_eq!(report.unclaimed_total, 2 * u64::MAX as u128);
+}
+
+#[test]
+fn test_events_without_id_claim_duplicates_in_order() {
+    let duplicate = Allocation {
+        address: "0x7897897897897897897897897897897897897890".to_string(),
+        amount: 400,
+        timestamp: "0xA".to_string(),
+        id: 1,
+    };
+    let tree = MerkleTree::new(vec![
+        duplicate.clone(),
+        Allocation {
+            id: 2,
+            ..duplicate.clone()
+        },
+    ]);
+
+    let claim = event("0x7897897897897897897897897897897897897890", 400, "0xa");
+    let mut tracker = ClaimTracker::new(&tree);
+
+    tracker.ingest(vec![claim.clone()]);
+    assert!(tracker.is_claimed(0));
+    assert!(!tracker.is_claimed(1));
+
+    tracker.ingest(vec![claim.clone(), claim.clone()]);
+    assert!(tracker.is_claimed(1));
+    assert_eq!(tracker.unmatched(), &[claim]);
+}
+
+#[test]
+fn test_invalid_events_are_unmatched() {
+    let tree = MerkleTree::new(load_mock_data("mock_allocations_second_wave.json"));
+    let invalid = event("0xnothex", 500, "0x4");
+
+    let mut tracker = ClaimTracker::new(&tree);
+    tracker.ingest(vec![invalid.clone()]);
+
+    assert_eq!(tracker.unmatched(), &[invalid]);
+    assert!(tracker.claimed_allocations().is_empty());
+}
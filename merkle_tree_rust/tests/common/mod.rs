@@ -0,0 +1,44 @@
+//! Fixtures shared by the integration tests; each test crate uses only some of them.
+#![allow(dead_code)]
+
+use merkle_tree::core::allocation::Allocation;
+use std::fs;
+
+/// Loads one of the mock allocation files in `data/`.
+pub fn load_mock_data(filename: &str) -> Vec<Allocation> {
+    let data = fs::read_to_string(format!("data/{}", filename)).expect("Failed to read mock data");
+    serde_json::from_str(&data).expect("Failed to parse mock data")
+}
+
+/// Builds an allocation from its fields.
+pub fn allocation(address: &str, amount: u64, timestamp: &str, id: u64) -> Allocation {
+    Allocation {
+        address: address.to_string(),
+        amount,
+        timestamp: timestamp.to_string(),
+        id,
+    }
+}
+
+/// Three allocations at distinct addresses, the last written in uppercase hex.
+pub fn sample_allocations() -> Vec<Allocation> {
+    vec![
+        allocation("0x1234567890abcdef1234567890abcdef12345678", 150, "0x2", 1),
+        allocation("0xabcdefabcdefabcdefabcdefabcdefabcdef1234", 200, "0x3", 2),
+        allocation("0x3F5A1E9DAB72F1A8C12D4D9B3A58A7B4425E7B4C", 250, "0x4", 5),
+    ]
+}
+
+/// `count` allocations with distinct addresses, amounts, timestamps and ids.
+pub fn generated_allocations(count: u64) -> Vec<Allocation> {
+    (0..count)
+        .map(|i| {
+            allocation(
+                &format!("0x{:x}", 0x1000 + i * 7),
+                100 + i,
+                &format!("0x{:x}", 1700000000 + i),
+                i,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::{allocation, load_mock_data};
+use merkle_tree::core::diff::ModifiedAllocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use std::process::Command;
+
+#[test]
+fn test_diff_identical_trees() {
+    let allocations = load_mock_data("mock_allocations_first_wave.json");
+    let tree = MerkleTree::new(allocations.clone());
+    let diff = tree.diff(&MerkleTree::new(allocations));
+
+    assert_eq!(diff.old_root, diff.new_root);
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert!(diff.modified.is_empty());
+    assert_eq!(diff.changed_proofs, 0);
+}
+
+#[test]
+fn test_diff_added_removed_and_modified() {
+    let kept = allocation("0x1234567890abcdef1234567890abcdef12345678", 150, "0x2", 1);
+    let removed = allocation("0xabcdefabcdefabcdefabcdefabcdefabcdef1234", 200, "0x3", 2);
+    let before = allocation("0x3F5A1E9DAB72F1A8C12D4D9B3A58A7B4425E7B4C", 250, "0x4", 5);
+    let after = allocation("0x3F5A1E9DAB72F1A8C12D4D9B3A58A7B4425E7B4C", 300, "0x4", 5);
+    let added = allocation("0x7897897897897897897897897897897897897890", 400, "0xA", 1);
+
+    let old_tree = MerkleTree::new(vec![kept.clone(), removed.clone(), before.clone()]);
+    let new_tree = MerkleTree::new(vec![kept.clone(), after.clone(), added.clone()]);
+    let diff = old_tree.diff(&new_tree);
+
+    assert_eq!(diff.added, vec![added]);
+    assert_eq!(diff.removed, vec![removed]);
+    assert_eq!(diff.modified, vec![ModifiedAllocation { before, after }]);
+    // The kept allocation gets a new proof, the modified one always does
+    assert_eq!(diff.changed_proofs, 2);
+}
+
+#[test]
+fn test_diff_merged_wave() {
+    let first_wave = load_mock_data("mock_allocations_first_wave.json");
+    let second_wave = load_mock_data("mock_allocations_second_wave.json");
+    let first_tree = MerkleTree::new(first_wave.clone());
+    let merged_tree = first_tree.merge_merkle_trees(second_wave.clone());
+
+    let diff = first_tree.diff(&merged_tree);
+    assert_eq!(diff.added, second_wave);
+    assert!(diff.removed.is_empty());
+    assert!(diff.modified.is_empty());
+    assert_eq!(diff.changed_proofs, first_wave.len());
+}
+
+#[test]
+fn test_cli_diff_outputs_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_merkle_tree_cli"))
+        .args([
+            "diff",
+            "data/mock_allocations_first_wave.json",
+            "data/mock_allocations_second_wave.json",
+        ])
+        .output()
+        .expect("Failed to run merkle_tree_cli");
+    assert!(output.status.success());
+
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Invalid JSON");
+    assert_eq!(
+        diff["added"].as_array().unwrap().len(),
+        load_mock_data("mock_allocations_second_wave.json").len()
+    );
+    assert_eq!(
+        diff["removed"].as_array().unwrap().len(),
+        load_mock_data("mock_allocations_first_wave.json").len()
+    );
+    assert_eq!(diff["changed_proofs"], 0);
+}
+
+#[test]
+fn test_cli_usage_error() {
+    let output = Command::new(env!("CARGO_BIN_EXE_merkle_tree_cli"))
+        .args(["diff", "only_one.json"])
+        .output()
+        .expect("Failed to run merkle_tree_cli");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Usage:"));
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "evm")]
+
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::{LeafOrdering, MerkleTree};
+use merkle_tree::evm::{
+    hash_pair, leaf_hash, parse_bytes32, to_hex, verify_proof, Bytes32, EvmMerkleTree,
+};
+use serde::Deserialize;
+use std::fs;
+
+const VECTORS_PATH: &str = "../test_vectors/evm_v1.json";
+
+#[derive(Deserialize)]
+struct TestVectors {
+    cases: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+struct TestCase {
+    name: String,
+    allocations: Vec<Allocation>,
+    leaves: Vec<String>,
+    root: String,
+    proofs: Vec<Vec<String>>,
+}
+
+fn load_vectors() -> TestVectors {
+    let data = fs::read_to_string(VECTORS_PATH).expect("Failed to read test vectors");
+    serde_json::from_str(&data).expect("Failed to parse test vectors")
+}
+
+fn sample_allocation() -> Allocation {
+    Allocation {
+        address: "0x5B38Da6a701c568545dCfcB03FcB875f56beddC4".to_string(),
+        amount: 1000,
+        timestamp: "0x6553f100".to_string(),
+        id: 1,
+    }
+}
+
+#[test]
+fn test_vectors_match_reference() {
+    for case in load_vectors().cases {
+        let tree = EvmMerkleTree::new(case.allocations.clone()).unwrap();
+        assert_eq!(to_hex(&tree.root()), case.root, "{}", case.name);
+
+        for ((allocation, leaf), proof) in case
+            .allocations
+            .iter()
+            .zip(case.leaves.iter())
+            .zip(case.proofs.iter())
+        {
+            assert_eq!(
+                to_hex(&leaf_hash(allocation).unwrap()),
+                *leaf,
+                "{}",
+                case.name
+            );
+            assert_eq!(tree.proof_hex(allocation).unwrap(), *proof, "{}", case.name);
+        }
+    }
+}
+
+#[test]
+fn test_every_proof_verifies() {
+    for case in load_vectors().cases {
+        let tree = EvmMerkleTree::new(case.allocations.clone()).unwrap();
+
+        for allocation in case.allocations.iter() {
+            let proof = tree.proof(allocation).unwrap();
+            let leaf = leaf_hash(allocation).unwrap();
+            assert!(verify_proof(tree.root(), leaf, &proof), "{}", case.name);
+
+            let mut tampered = leaf;
+            tampered[31] ^= 1;
+            assert!(
+                !verify_proof(tree.root(), tampered, &proof),
+                "{}",
+                case.name
+            );
+        }
+    }
+}
+
+#[test]
+fn test_leaf_is_keccak_of_abi_encoding() {
+    // keccak256(abi.encode(0x5B38Da6a701c568545dCfcB03FcB875f56beddC4, 1000, 1700000000, 1))
+    assert_eq!(
+        to_hex(&leaf_hash(&sample_allocation()).unwrap()),
+        "0xc3751bd972353bc1acc4ebc0e896936bdd0f48f2c86e61d4586370ea81324b86"
+    );
+}
+
+#[test]
+fn test_hash_pair_is_commutative() {
+    let a: Bytes32 = [1u8; 32];
+    let b: Bytes32 = [2u8; 32];
+    assert_eq!(hash_pair(&a, &b), hash_pair(&b, &a));
+    assert_ne!(hash_pair(&a, &b), hash_pair(&a, &a));
+}
+
+#[test]
+fn test_from_tree_keeps_leaf_order() {
+    let allocations: Vec<Allocation> = load_vectors().cases.pop().unwrap().allocations;
+    let tree = MerkleTree::with_ordering(allocations.clone(), LeafOrdering::LeafHash);
+
+    let evm_tree = EvmMerkleTree::from_tree(&tree).unwrap();
+    assert_eq!(evm_tree.get_allocations(), tree.get_allocations());
+    for allocation in allocations.iter() {
+        let proof = evm_tree.proof(allocation).unwrap();
+        assert!(verify_proof(
+            evm_tree.root(),
+            leaf_hash(allocation).unwrap(),
+            &proof
+        ));
+    }
+
+    let mut missing = sample_allocation();
+    missing.amount += 1;
+    assert_eq!(
+        evm_tree.proof(&missing).err().unwrap(),
+        "Allocation not found"
+    );
+}
+
+#[test]
+fn test_rejects_starknet_addresses() {
+    let mut allocation = sample_allocation();
+    allocation.address =
+        "0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7".to_string();
+
+    assert_eq!(
+        leaf_hash(&allocation).err().unwrap(),
+        "Address does not fit in 20 bytes"
+    );
+    assert!(EvmMerkleTree::new(vec![allocation]).is_err());
+    assert_eq!(
+        EvmMerkleTree::new(vec![]).err().unwrap(),
+        "Tree has no allocations"
+    );
+}
+
+#[test]
+fn test_bytes32_hex_round_trips() {
+    let word = leaf_hash(&sample_allocation()).unwrap();
+    assert_eq!(parse_bytes32(&to_hex(&word)).unwrap(), word);
+    assert_eq!(to_hex(&word).len(), 66);
+
+    assert!(parse_bytes32("0x1234").is_err());
+    assert!(parse_bytes32(&format!("0x{}", "zz".repeat(32))).is_err());
+}
@@ -0,0 +1,220 @@
+mod common;
+
+use common::allocation;
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::ids::{assign_ids, check_collisions, IdMode};
+use merkle_tree::core::merkle_tree::MerkleTree;
+use starknet::core::types::Felt;
+use starknet_crypto::poseidon_hash_many;
+
+fn sample_allocations() -> Vec<Allocation> {
+    vec![
+        allocation("0xabc", 100, "0x1", 0),
+        allocation("0x123", 50, "0x1", 0),
+        allocation("0xabc", 100, "0x1", 0),
+        allocation("0xABC", 25, "0x2", 0),
+    ]
+}
+
+fn ids(allocations: &[Allocation]) -> Vec<u64> {
+    allocations.iter().map(|a| a.id).collect()
+}
+
+#[test]
+fn test_per_address_ids() {
+    let assigned = assign_ids(sample_allocations(), IdMode::PerAddress, None).unwrap();
+
+    assert_eq!(ids(&assigned), vec![1, 1, 2, 3]);
+    assert_eq!(assigned[3].address, "0xABC");
+}
+
+#[test]
+fn test_global_ids() {
+    let assigned = assign_ids(sample_allocations(), IdMode::Global, None).unwrap();
+
+    assert_eq!(ids(&assigned), vec![1, 2, 3, 4]);
+}
+
+fn content_hash(address: &str, amount: u64, timestamp: u64, occurrence: u64) -> u64 {
+    let hash = poseidon_hash_many(&[
+        Felt::from_hex(address).unwrap(),
+        Felt::from(amount),
+        Felt::from(timestamp),
+        Felt::from(occurrence),
+    ]);
+    u64::from_be_bytes(hash.to_bytes_be()[24..].try_into().unwrap())
+}
+
+#[test]
+fn test_content_hash_ids() {
+    let mut allocations = sample_allocations();
+    allocations.remove(2);
+
+    let assigned = assign_ids(allocations.clone(), IdMode::ContentHash, None).unwrap();
+    assert_eq!(assigned[0].id, content_hash("0xabc", 100, 1, 0));
+
+    allocations.reverse();
+    let reversed = assign_ids(allocations, IdMode::ContentHash, None).unwrap();
+    assert_eq!(reversed[2].id, assigned[0].id);
+}
+
+#[test]
+fn test_content_hash_separates_identical_allocations() {
+    let assigned = assign_ids(sample_allocations(), IdMode::ContentHash, None).unwrap();
+
+    assert_eq!(assigned[0].id, content_hash("0xabc", 100, 1, 0));
+    assert_eq!(assigned[2].id, content_hash("0xabc", 100, 1, 1));
+    assert_ne!(assigned[0].id, assigned[2].id);
+    assert_eq!(assigned[3].id, content_hash("0xabc", 25, 2, 0));
+}
+
+#[test]
+fn test_ids_continue_after_previous_tree() {
+    let previous =
+        MerkleTree::new(assign_ids(sample_allocations(), IdMode::PerAddress, None).unwrap());
+    let next_wave = vec![
+        allocation("0x0abc", 100, "0x1", 0),
+        allocation("0x456", 10, "0x3", 0),
+    ];
+
+    let per_address = assign_ids(next_wave.clone(), IdMode::PerAddress, Some(&previous)).unwrap();
+    assert_eq!(ids(&per_address), vec![4, 1]);
+
+    let global = assign_ids(next_wave, IdMode::Global, Some(&previous)).unwrap();
+    assert_eq!(ids(&global), vec![4, 5]);
+}
+
+#[test]
+fn test_previous_tree_collisions_are_rejected() {
+    let published = vec![allocation("0xabc", 100, "0x1", 0)];
+    let previous = MerkleTree::new(assign_ids(published, IdMode::ContentHash, None).unwrap());
+
+    let error = assign_ids(
+        vec![allocation("0x00ABC", 100, "0x01", 0)],
+        IdMode::ContentHash,
+        Some(&previous),
+    )
+    .err()
+    .unwrap();
+    assert!(error.starts_with("Allocation for address 0xabc with id "));
+    assert!(error.ends_with(" is already published"));
+
+    assert!(assign_ids(
+        vec![allocation("0xabc", 101, "0x1", 0)],
+        IdMode::ContentHash,
+        Some(&previous)
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_ids_after_largest_previous_id_overflow() {
+    let previous = MerkleTree::new(vec![allocation("0xabc", 100, "0x1", u64::MAX)]);
+
+    assert_eq!(
+        assign_ids(
+            vec![allocation("0x456", 10, "0x3", 0)],
+            IdMode::Global,
+            Some(&previous)
+        )
+        .err()
+        .unwrap(),
+        "Id overflow"
+    );
+
+    // Only the address that used the last id runs out
+    let ids = assign_ids(
+        vec![allocation("0x456", 10, "0x3", 0)],
+        IdMode::PerAddress,
+        Some(&previous),
+    )
+    .unwrap();
+    assert_eq!(ids[0].id, 1);
+    assert_eq!(
+        assign_ids(
+            vec![allocation("0xabc", 10, "0x3", 0)],
+            IdMode::PerAddress,
+            Some(&previous)
+        )
+        .err()
+        .unwrap(),
+        "Id overflow for address 0xabc"
+    );
+
+    // Content hashes do not continue after published ids
+    assert!(assign_ids(
+        vec![allocation("0x456", 10, "0x3", 0)],
+        IdMode::ContentHash,
+        Some(&previous)
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_ids_overflow_while_assigning() {
+    let previous = MerkleTree::new(vec![allocation("0xabc", 100, "0x1", u64::MAX - 1)]);
+    let allocations = vec![
+        allocation("0xabc", 10, "0x3", 0),
+        allocation("0xabc", 20, "0x4", 0),
+    ];
+
+    let ids = assign_ids(allocations[..1].to_vec(), IdMode::Global, Some(&previous)).unwrap();
+    assert_eq!(ids[0].id, u64::MAX);
+    assert_eq!(
+        assign_ids(allocations.clone(), IdMode::Global, Some(&previous))
+            .err()
+            .unwrap(),
+        "Id overflow"
+    );
+    assert_eq!(
+        assign_ids(allocations, IdMode::PerAddress, Some(&previous))
+            .err()
+            .unwrap(),
+        "Id overflow for address 0xabc"
+    );
+}
+
+#[test]
+fn test_check_collisions() {
+    let mut allocations = sample_allocations();
+    allocations[2].id = 2;
+
+    assert!(check_collisions(&allocations, &[]).is_ok());
+    assert!(check_collisions(&allocations[2..], &allocations[..1]).is_ok());
+
+    allocations[2].id = 0;
+    assert_eq!(
+        check_collisions(&allocations[2..], &allocations[..1])
+            .err()
+            .unwrap(),
+        "Allocation for address 0xabc with id 0 is already published"
+    );
+    assert_eq!(
+        check_collisions(&allocations, &[]).err().unwrap(),
+        "Duplicate allocation for address 0xabc with id 0"
+    );
+}
+
+#[test]
+fn test_assign_ids_rejects_invalid_allocations() {
+    let mut allocations = sample_allocations();
+    allocations[1].timestamp = "later".to_string();
+
+    assert_eq!(
+        assign_ids(allocations, IdMode::Global, None).err().unwrap(),
+        "Invalid timestamp"
+    );
+}
+
+#[test]
+fn test_id_mode_json() {
+    assert_eq!(
+        serde_json::to_value(IdMode::ContentHash).unwrap(),
+        "content_hash"
+    );
+    assert_eq!(
+        serde_json::from_value::<IdMode>("per_address".into()).unwrap(),
+        IdMode::PerAddress
+    );
+    assert_eq!(IdMode::default(), IdMode::PerAddress);
+}
@@ -263,4 +263,32 @@ mod integration_tests {
             "Tree root should remain unchanged"
         );
     }
+
+    #[test]
+    fn test_proofs_for_address_with_multiple_allocations() {
+        let first_wave = load_mock_data("mock_allocations_first_wave.json");
+        let tree = MerkleTree::new(first_wave.clone());
+        let address = "0xabcdefabcdefabcdefabcdefabcdefabcdef1234";
+
+        let allocations = tree.allocations_for(address);
+        assert_eq!(allocations.len(), 4, "Address should hold four allocations");
+        assert!(allocations.iter().all(|a| a.address == address));
+
+        let proofs = tree
+            .proofs_for(address)
+            .expect("Failed to generate proofs for address");
+        assert_eq!(proofs.len(), allocations.len());
+
+        for (proof, allocation) in proofs.iter().zip(allocations) {
+            let expected = tree
+                .build_address_calldata(
+                    &allocation.address,
+                    allocation.amount,
+                    &allocation.timestamp,
+                    allocation.id,
+                )
+                .unwrap();
+            assert_eq!(proof, &expected, "Proof should match direct lookup");
+        }
+    }
 }
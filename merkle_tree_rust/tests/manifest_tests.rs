@@ -0,0 +1,141 @@
+mod common;
+
+use common::sample_allocations;
+use merkle_tree::core::manifest::{input_digest, BuildManifest, HashMode, OddLeafPolicy};
+use merkle_tree::core::merkle_tree::{LeafOrdering, MerkleTree};
+
+#[test]
+fn test_manifest_describes_build() {
+    let (tree, manifest) =
+        BuildManifest::build(sample_allocations(), LeafOrdering::Insertion).unwrap();
+
+    assert_eq!(manifest.crate_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(
+        manifest.input_digest,
+        input_digest(&sample_allocations()).unwrap()
+    );
+    assert_eq!(manifest.hash_mode, HashMode::Pedersen);
+    assert_eq!(manifest.ordering, LeafOrdering::Insertion);
+    assert_eq!(manifest.odd_leaf_policy, OddLeafPolicy::DuplicateLast);
+    assert_eq!(manifest.leaf_count, 3);
+    assert_eq!(manifest.total_amount, 600);
+    assert_eq!(manifest.root, tree.root.value);
+    assert_eq!(
+        manifest.root,
+        MerkleTree::new(sample_allocations()).root.value
+    );
+}
+
+#[test]
+fn test_input_digest_ignores_spelling() {
+    let mut respelled = sample_allocations();
+    respelled[0].address = "0x001234567890ABCDEF1234567890ABCDEF12345678".to_string();
+    respelled[2].timestamp = "0x04".to_string();
+
+    assert_eq!(
+        input_digest(&respelled).unwrap(),
+        input_digest(&sample_allocations()).unwrap()
+    );
+}
+
+#[test]
+fn test_input_digest_covers_order_and_fields() {
+    let digest = input_digest(&sample_allocations()).unwrap();
+
+    let mut reordered = sample_allocations();
+    reordered.swap(0, 1);
+    assert_ne!(input_digest(&reordered).unwrap(), digest);
+
+    let mut amount = sample_allocations();
+    amount[1].amount += 1;
+    assert_ne!(input_digest(&amount).unwrap(), digest);
+
+    assert_ne!(input_digest(&sample_allocations()[..2]).unwrap(), digest);
+}
+
+#[test]
+fn test_sorted_ordering_ignores_input_order() {
+    let mut reordered = sample_allocations();
+    reordered.reverse();
+
+    let (_, manifest) = BuildManifest::build(sample_allocations(), LeafOrdering::LeafHash).unwrap();
+    let (_, other) = BuildManifest::build(reordered.clone(), LeafOrdering::LeafHash).unwrap();
+    assert_eq!(manifest, other);
+    assert!(manifest.reproduce(reordered).is_ok());
+}
+
+#[test]
+fn test_reproduce_confirms_manifest() {
+    let (tree, manifest) =
+        BuildManifest::build(sample_allocations(), LeafOrdering::AddressTimestampId).unwrap();
+
+    let reproduced = manifest.reproduce(sample_allocations()).unwrap();
+    assert_eq!(reproduced.root.value, tree.root.value);
+    assert_eq!(reproduced.ordering(), LeafOrdering::AddressTimestampId);
+}
+
+#[test]
+fn test_reproduce_reports_mismatches() {
+    let (_, manifest) =
+        BuildManifest::build(sample_allocations(), LeafOrdering::Insertion).unwrap();
+    let mut changed = sample_allocations();
+    changed[1].amount = 201;
+
+    let error = manifest.reproduce(changed).err().unwrap();
+    assert!(error.starts_with("Build does not match manifest:"));
+    assert!(error.contains("input_digest: expected"));
+    assert!(error.contains("total_amount: expected 600, got 601"));
+    assert!(error.contains("root: expected"));
+    assert!(!error.contains("leaf_count"));
+
+    let mut reordered = sample_allocations();
+    reordered.swap(0, 2);
+    assert!(manifest.reproduce(reordered).is_err());
+}
+
+#[test]
+fn test_reproduce_accepts_other_crate_versions() {
+    let (_, mut manifest) =
+        BuildManifest::build(sample_allocations(), LeafOrdering::Insertion).unwrap();
+    manifest.crate_version = "0.0.1".to_string();
+
+    assert!(manifest.reproduce(sample_allocations()).is_ok());
+}
+
+#[test]
+fn test_build_rejects_invalid_input() {
+    assert_eq!(
+        BuildManifest::build(vec![], LeafOrdering::Insertion)
+            .err()
+            .unwrap(),
+        "Tree has no allocations"
+    );
+
+    let mut invalid = sample_allocations();
+    invalid[0].address = "not hex".to_string();
+    assert_eq!(
+        BuildManifest::build(invalid, LeafOrdering::Insertion)
+            .err()
+            .unwrap(),
+        "Invalid address"
+    );
+}
+
+#[test]
+fn test_manifest_json_round_trips() {
+    let (_, manifest) = BuildManifest::build(sample_allocations(), LeafOrdering::LeafHash).unwrap();
+    let json = serde_json::to_value(&manifest).unwrap();
+
+    assert_eq!(json["hash_mode"], "pedersen");
+    assert_eq!(json["ordering"], "leaf_hash");
+    assert_eq!(json["odd_leaf_policy"], "duplicate_last");
+    assert_eq!(json["total_amount"], 600);
+    assert_eq!(
+        serde_json::from_value::<BuildManifest>(json.clone()).unwrap(),
+        manifest
+    );
+
+    let mut unknown = json;
+    unknown["hash_mode"] = "keccak".into();
+    assert!(serde_json::from_value::<BuildManifest>(unknown).is_err());
+}
@@ -1,6 +1,7 @@
 use merkle_tree::core::allocation::Allocation;
-use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::merkle_tree::{LeafOrdering, MerkleTree};
 use num_traits::Zero;
+use starknet::core::types::Felt;
 
 #[test]
 fn test_create_merkle_tree() {
@@ -103,6 +104,7 @@ fn test_build_address_calldata() {
 
 #[test]
 #[should_panic(expected = "Failed to generate calldata")]
+#[allow(unused_variables)]
 fn test_build_address_calldata_with_invalid_allocation() {
     let allocations = vec![Allocation {
         address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
@@ -166,3 +168,254 @@ fn test_merge_merkle_trees() {
     assert!(!merged_tree.root.value.is_zero());
     assert_eq!(merged_tree.get_allocations().len(), 4);
 }
+
+#[test]
+fn test_allocations_for_address() {
+    let allocations = vec![
+        Allocation {
+            address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            amount: 150,
+            timestamp: "0x2".to_string(),
+            id: 1,
+        },
+        Allocation {
+            address: "0xabcdefabcdefabcdefabcdefabcdefabcdef1234".to_string(),
+            amount: 200,
+            timestamp: "0x3".to_string(),
+            id: 1,
+        },
+        Allocation {
+            address: "0x1234567890ABCDEF1234567890ABCDEF12345678".to_string(),
+            amount: 300,
+            timestamp: "0x4".to_string(),
+            id: 2,
+        },
+    ];
+
+    let tree = MerkleTree::new(allocations.clone());
+
+    // Addresses are matched by value, regardless of hex casing
+    let found = tree.allocations_for("0x1234567890abcdef1234567890abcdef12345678");
+    assert_eq!(found, vec![&allocations[0], &allocations[2]]);
+
+    assert!(tree
+        .allocations_for("0xdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef")
+        .is_empty());
+    assert!(tree.allocations_for("0xnotanaddress").is_empty());
+}
+
+#[test]
+fn test_proofs_for_address() {
+    let allocations = vec![
+        Allocation {
+            address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            amount: 150,
+            timestamp: "0x2".to_string(),
+            id: 1,
+        },
+        Allocation {
+            address: "0xabcdefabcdefabcdefabcdefabcdefabcdef1234".to_string(),
+            amount: 200,
+            timestamp: "0x3".to_string(),
+            id: 1,
+        },
+        Allocation {
+            address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            amount: 300,
+            timestamp: "0x4".to_string(),
+            id: 2,
+        },
+    ];
+
+    let tree = MerkleTree::new(allocations.clone());
+    let proofs = tree
+        .proofs_for("0x1234567890abcdef1234567890abcdef12345678")
+        .expect("Failed to generate proofs");
+
+    assert_eq!(proofs.len(), 2);
+    for (proof, allocation) in proofs.iter().zip([&allocations[0], &allocations[2]]) {
+        let expected = tree
+            .build_address_calldata(
+                &allocation.address,
+                allocation.amount,
+                &allocation.timestamp,
+                allocation.id,
+            )
+            .unwrap();
+        assert_eq!(proof, &expected);
+    }
+
+    assert!(tree
+        .proofs_for("0xdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef")
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        tree.proofs_for("0xnotanaddress").unwrap_err(),
+        "Invalid address"
+    );
+}
+
+#[test]
+fn test_serialize_and_deserialize_tree() {
+    let allocations = vec![
+        Allocation {
+            address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            amount: 150,
+            timestamp: "0x2".to_string(),
+            id: 1,
+        },
+        Allocation {
+            address: "0xabcdefabcdefabcdefabcdefabcdefabcdef1234".to_string(),
+            amount: 200,
+            timestamp: "0x3".to_string(),
+            id: 2,
+        },
+    ];
+
+    let tree = MerkleTree::new(allocations.clone());
+    let json = serde_json::to_string(&tree).expect("Failed to serialize tree");
+    let restored: MerkleTree = serde_json::from_str(&json).expect("Failed to deserialize tree");
+
+    assert_eq!(restored.root.value, tree.root.value);
+    assert_eq!(restored.get_allocations(), &allocations);
+}
+
+#[test]
+fn test_deserialize_tree_with_wrong_root() {
+    let json = r#"{
+        "root": "0x1",
+        "allocations": [
+            {"address": "0x1234567890abcdef1234567890abcdef12345678", "amount": 150, "timestamp": "0x2", "id": 1}
+        ]
+    }"#;
+
+    let result = serde_json::from_str::<MerkleTree>(json);
+    assert!(result
+        .err()
+        .unwrap()
+        .to_string()
+        .contains("Root does not match allocations"));
+}
+
+#[test]
+fn test_deserialize_tree_with_invalid_allocation() {
+    let json = r#"{
+        "root": "0x1",
+        "allocations": [
+            {"address": "0x1234567dhiodhaoo", "amount": 150, "timestamp": "0x2", "id": 1}
+        ]
+    }"#;
+
+    let result = serde_json::from_str::<MerkleTree>(json);
+    assert!(result
+        .err()
+        .unwrap()
+        .to_string()
+        .contains("Invalid address"));
+}
+
+fn unordered_allocations() -> Vec<Allocation> {
+    vec![
+        Allocation {
+            address: "0xA".to_string(),
+            amount: 100,
+            timestamp: "0x2".to_string(),
+            id: 1,
+        },
+        Allocation {
+            address: "0x9".to_string(),
+            amount: 200,
+            timestamp: "0x3".to_string(),
+            id: 2,
+        },
+        Allocation {
+            address: "0x9".to_string(),
+            amount: 300,
+            timestamp: "0x1".to_string(),
+            id: 3,
+        },
+    ]
+}
+
+#[test]
+fn test_sorted_orderings_ignore_input_order() {
+    let allocations = unordered_allocations();
+    let mut reversed = allocations.clone();
+    reversed.reverse();
+
+    for ordering in [LeafOrdering::LeafHash, LeafOrdering::AddressTimestampId] {
+        let tree = MerkleTree::with_ordering(allocations.clone(), ordering);
+        let reversed_tree = MerkleTree::with_ordering(reversed.clone(), ordering);
+
+        assert_eq!(tree.root.value, reversed_tree.root.value);
+        assert_eq!(tree.get_allocations(), reversed_tree.get_allocations());
+        assert_eq!(tree.ordering(), ordering);
+    }
+
+    assert_ne!(
+        MerkleTree::new(allocations).root.value,
+        MerkleTree::new(reversed).root.value
+    );
+}
+
+#[test]
+fn test_address_timestamp_id_ordering() {
+    let tree = MerkleTree::with_ordering(unordered_allocations(), LeafOrdering::AddressTimestampId);
+
+    // Addresses compare as felts, not as strings
+    let ids: Vec<u64> = tree.get_allocations().iter().map(|a| a.id).collect();
+    assert_eq!(ids, vec![3, 2, 1]);
+}
+
+#[test]
+fn test_leaf_hash_ordering() {
+    let tree = MerkleTree::with_ordering(unordered_allocations(), LeafOrdering::LeafHash);
+    let leaves: Vec<Felt> = tree
+        .get_allocations()
+        .iter()
+        .map(|a| a.leaf_hash().unwrap())
+        .collect();
+
+    assert!(leaves.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_ordering_is_serialized() {
+    let tree = MerkleTree::with_ordering(unordered_allocations(), LeafOrdering::LeafHash);
+    let json = serde_json::to_value(&tree).unwrap();
+    assert_eq!(json["ordering"], "leaf_hash");
+
+    let restored: MerkleTree = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.ordering(), LeafOrdering::LeafHash);
+    assert_eq!(restored.root.value, tree.root.value);
+
+    // Merging keeps the ordering, so the root stays independent of input order
+    let extra = Allocation {
+        address: "0x1".to_string(),
+        amount: 50,
+        timestamp: "0x1".to_string(),
+        id: 4,
+    };
+    let mut all = unordered_allocations();
+    all.insert(0, extra.clone());
+    assert_eq!(
+        restored.merge_merkle_trees(vec![extra]).root.value,
+        MerkleTree::with_ordering(all, LeafOrdering::LeafHash)
+            .root
+            .value
+    );
+}
+
+#[test]
+fn test_deserialize_tree_without_ordering() {
+    let tree = MerkleTree::new(unordered_allocations());
+    let json = format!(
+        r#"{{"root": "{:#x}", "allocations": {}}}"#,
+        tree.root.value,
+        serde_json::to_string(tree.get_allocations()).unwrap()
+    );
+
+    let restored: MerkleTree = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.ordering(), LeafOrdering::Insertion);
+    assert_eq!(restored.root.value, tree.root.value);
+}
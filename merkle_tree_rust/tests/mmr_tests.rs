@@ -0,0 +1,178 @@
+mod common;
+
+use common::generated_allocations;
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::mmr::{
+    bag_peaks, verify_mmr_proof, MerkleMountainRange, MmrHasher, MmrProof,
+};
+use starknet::core::types::Felt;
+
+const HASHERS: [MmrHasher; 2] = [MmrHasher::Pedersen, MmrHasher::Poseidon];
+
+fn leaf(mmr: &MerkleMountainRange, index: usize) -> Felt {
+    mmr.get_allocations()[index].leaf_hash().unwrap()
+}
+
+fn claimer_allocations() -> Vec<Allocation> {
+    vec![
+        Allocation {
+            address: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            amount: 150,
+            timestamp: "0x2".to_string(),
+            id: 1,
+        },
+        Allocation {
+            address: "0xabcdefabcdefabcdefabcdefabcdefabcdef1234".to_string(),
+            amount: 200,
+            timestamp: "0x3".to_string(),
+            id: 2,
+        },
+        Allocation {
+            address: "0x3F5A1E9DAB72F1A8C12D4D9B3A58A7B4425E7B4C".to_string(),
+            amount: 250,
+            timestamp: "0x4".to_string(),
+            id: 5,
+        },
+    ]
+}
+
+#[test]
+fn test_roots_match_cairo() {
+    // Same vectors as merkle_tree_cairo/tests/tests_mmr.cairo
+    let expected = [
+        (
+            MmrHasher::Pedersen,
+            "0x1578caa6abdd4e491d7d88c1ef93ddf9a59929fdcc8d347cc76eb3c4c08c36c",
+        ),
+        (
+            MmrHasher::Poseidon,
+            "0x6532265ec84a3516941818fd81c39ef1fd656c32e8bbf7ca691e333d04c69ac",
+        ),
+    ];
+    for (hasher, root) in expected {
+        let mmr = MerkleMountainRange::from_allocations(claimer_allocations(), hasher).unwrap();
+        assert_eq!(mmr.root().unwrap(), Felt::from_hex(root).unwrap());
+    }
+}
+
+#[test]
+fn test_one_peak_per_mountain() {
+    let mut mmr = MerkleMountainRange::new(MmrHasher::Pedersen);
+    assert!(mmr.root().is_err());
+
+    for (i, allocation) in generated_allocations(20).into_iter().enumerate() {
+        assert_eq!(mmr.append(allocation).unwrap(), i);
+        assert_eq!(mmr.peaks().len(), (i + 1).count_ones() as usize);
+    }
+}
+
+#[test]
+fn test_full_mountain_matches_merkle_tree() {
+    let allocations = generated_allocations(8);
+    let mmr =
+        MerkleMountainRange::from_allocations(allocations.clone(), MmrHasher::Pedersen).unwrap();
+
+    assert_eq!(mmr.peaks(), vec![MerkleTree::new(allocations).root.value]);
+}
+
+#[test]
+fn test_every_proof_verifies() {
+    for hasher in HASHERS {
+        for count in 1..=17 {
+            let mmr = MerkleMountainRange::from_allocations(generated_allocations(count), hasher)
+                .unwrap();
+            let root = mmr.root().unwrap();
+
+            for i in 0..count as usize {
+                let proof = mmr.proof(i).unwrap();
+                assert!(verify_mmr_proof(hasher, root, leaf(&mmr, i), &proof));
+                assert_eq!(mmr.proof_for(&mmr.get_allocations()[i]).unwrap(), proof);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_appends_keep_old_proofs_as_prefix() {
+    for hasher in HASHERS {
+        let allocations = generated_allocations(23);
+        let mut mmr =
+            MerkleMountainRange::from_allocations(allocations[..5].to_vec(), hasher).unwrap();
+        let old_root = mmr.root().unwrap();
+        let old_proofs: Vec<MmrProof> = (0..5).map(|i| mmr.proof(i).unwrap()).collect();
+
+        for allocation in allocations[5..].iter() {
+            mmr.append(allocation.clone()).unwrap();
+        }
+        let root = mmr.root().unwrap();
+        assert_ne!(root, old_root);
+
+        for (i, old_proof) in old_proofs.iter().enumerate() {
+            // Old proofs still verify against the root they were issued for
+            assert!(verify_mmr_proof(hasher, old_root, leaf(&mmr, i), old_proof));
+
+            let updated = mmr.update_proof(old_proof).unwrap();
+            assert_eq!(updated, mmr.proof(i).unwrap());
+            assert!(updated.siblings.starts_with(&old_proof.siblings));
+            assert!(verify_mmr_proof(hasher, root, leaf(&mmr, i), &updated));
+        }
+    }
+}
+
+#[test]
+fn test_invalid_proofs_fail() {
+    let hasher = MmrHasher::Poseidon;
+    let mmr = MerkleMountainRange::from_allocations(generated_allocations(11), hasher).unwrap();
+    let root = mmr.root().unwrap();
+    let proof = mmr.proof(4).unwrap();
+
+    assert!(!verify_mmr_proof(hasher, root, leaf(&mmr, 5), &proof));
+    assert!(!verify_mmr_proof(
+        MmrHasher::Pedersen,
+        root,
+        leaf(&mmr, 4),
+        &proof
+    ));
+
+    let mut short = proof.clone();
+    short.siblings.pop();
+    assert!(!verify_mmr_proof(hasher, root, leaf(&mmr, 4), &short));
+
+    let mut count = proof.clone();
+    count.leaf_count = 12;
+    assert!(!verify_mmr_proof(hasher, root, leaf(&mmr, 4), &count));
+
+    let mut index = proof.clone();
+    index.leaf_index = 11;
+    assert!(!verify_mmr_proof(hasher, root, leaf(&mmr, 4), &index));
+}
+
+#[test]
+fn test_update_rejects_foreign_proofs() {
+    let mmr = MerkleMountainRange::from_allocations(generated_allocations(6), MmrHasher::Pedersen)
+        .unwrap();
+    let other = MerkleMountainRange::from_allocations(
+        generated_allocations(4)[1..].to_vec(),
+        MmrHasher::Pedersen,
+    )
+    .unwrap();
+
+    assert_eq!(
+        mmr.update_proof(&other.proof(0).unwrap()).err().unwrap(),
+        "Proof is not from this range"
+    );
+    let mut future = mmr.proof(0).unwrap();
+    future.leaf_count = 7;
+    assert!(mmr.update_proof(&future).is_err());
+    assert_eq!(mmr.proof(6).err().unwrap(), "Leaf index out of range");
+}
+
+#[test]
+fn test_bag_peaks_commits_to_leaf_count() {
+    let peaks = [Felt::from(1u8), Felt::from(2u8)];
+    assert_ne!(
+        bag_peaks(MmrHasher::Pedersen, 3, &peaks),
+        bag_peaks(MmrHasher::Pedersen, 5, &peaks)
+    );
+}
@@ -1,58 +1,40 @@
 use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::campaign::Campaign;
+use merkle_tree::core::manifest::BuildManifest;
 use merkle_tree::core::merkle_tree::MerkleTree;
 use serde_json::from_reader;
-use std::fs::{File, OpenOptions};
+use std::fs::{self, File, OpenOptions};
 use std::io::{BufReader, Write};
+use std::path::Path;
+
+// Fixed creation times, so regenerating output/campaign.json leaves it unchanged
+const FIRST_WAVE_CREATED_AT: u64 = 1717200000;
+const SECOND_WAVE_CREATED_AT: u64 = 1719792000;
 
 fn main() {
+    let mut campaign = Campaign::new("mock_allocations");
+
     // Step 1: Load first wave of allocations
     let file = File::open("data/mock_allocations_first_wave.json").expect("File not found");
     let reader = BufReader::new(file);
     let allocations_first_wave: Vec<Allocation> = from_reader(reader).expect("Error reading JSON");
 
     // Step 2: Create first Merkle tree
-    let tree_first_wave = MerkleTree::new(allocations_first_wave.clone());
-    let root_hash_first_wave = tree_first_wave.root.value.to_string();
+    campaign
+        .add_wave_at("first_wave", allocations_first_wave, FIRST_WAVE_CREATED_AT)
+        .expect("Invalid first wave");
+    let tree_first_wave = campaign.tree_at(0).expect("First wave not found");
 
     // Step 3: Write first wave Merkle tree data
-    let mut output_file_first = OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open("output/first_merkle_tree_data.txt")
-        .expect("Unable to open or create file");
-
-    writeln!(
-        output_file_first,
-        "Root Hash First Wave: {}\n",
-        root_hash_first_wave
-    )
-    .expect("Failed to write root hash");
-
-    for allocation in allocations_first_wave.iter() {
-        let proof = tree_first_wave
-            .build_address_calldata(
-                &allocation.address,
-                allocation.amount,
-                &allocation.timestamp,
-                allocation.id,
-            )
-            .expect("Proof generation failed");
-
-        writeln!(
-            output_file_first,
-            "Proof for address {}:",
-            allocation.address
-        )
-        .expect("Failed to write proof header");
-
-        for p in proof {
-            writeln!(output_file_first, "{}", p).expect("Failed to write proof");
-        }
-        writeln!(output_file_first).expect("Failed to write newline");
-    }
+    write_tree_data(
+        "output/first_merkle_tree_data.txt",
+        "Root Hash First Wave",
+        &tree_first_wave,
+    );
+    write_manifest("output/first_merkle_tree_manifest.json", &tree_first_wave);
 
     println!("First Merkle tree data saved in tests/first_merkle_tree_data.txt");
+    println!("First Merkle tree manifest saved in output/first_merkle_tree_manifest.json");
 
     // Step 4: Load second wave of allocations
     let file = File::open("data/mock_allocations_second_wave.json").expect("File not found");
@@ -60,29 +42,47 @@ fn main() {
     let allocations_second_wave: Vec<Allocation> = from_reader(reader).expect("Error reading JSON");
 
     // Step 5: Merge and create second Merkle tree
-    let mut combined_allocations = allocations_first_wave.clone();
-    combined_allocations.extend(allocations_second_wave.clone());
-
-    let tree_second_wave = MerkleTree::new(combined_allocations.clone());
-    let root_hash_second_wave = tree_second_wave.root.value.to_string();
+    campaign
+        .add_wave_at(
+            "second_wave",
+            allocations_second_wave,
+            SECOND_WAVE_CREATED_AT,
+        )
+        .expect("Invalid second wave");
+    let tree_second_wave = campaign.tree_at(1).expect("Second wave not found");
 
     // Step 6: Write second wave Merkle tree data
-    let mut output_file_second = OpenOptions::new()
+    write_tree_data(
+        "output/second_merkle_tree_data.txt",
+        "Root Hash Second Wave",
+        &tree_second_wave,
+    );
+    write_manifest("output/second_merkle_tree_manifest.json", &tree_second_wave);
+
+    println!("Second Merkle tree data saved in tests/second_merkle_tree_data.txt");
+    println!("Second Merkle tree manifest saved in output/second_merkle_tree_manifest.json");
+
+    // Step 7: Record the wave history so every root can be reproduced
+    campaign
+        .save(Path::new("output/campaign.json"))
+        .expect("Failed to save campaign");
+
+    println!("Campaign history saved in output/campaign.json");
+}
+
+/// Writes the root of a tree followed by the calldata of each of its allocations.
+fn write_tree_data(path: &str, header: &str, tree: &MerkleTree) {
+    let mut output_file = OpenOptions::new()
         .write(true)
         .create(true)
         .truncate(true)
-        .open("output/second_merkle_tree_data.txt")
+        .open(path)
         .expect("Unable to open or create file");
 
-    writeln!(
-        output_file_second,
-        "Root Hash Second Wave: {}\n",
-        root_hash_second_wave
-    )
-    .expect("Failed to write root hash");
+    writeln!(output_file, "{}: {}\n", header, tree.root.value).expect("Failed to write root hash");
 
-    for allocation in combined_allocations.iter() {
-        let proof = tree_second_wave
+    for allocation in tree.get_allocations().iter() {
+        let proof = tree
             .build_address_calldata(
                 &allocation.address,
                 allocation.amount,
@@ -91,18 +91,19 @@ fn main() {
             )
             .expect("Proof generation failed");
 
-        writeln!(
-            output_file_second,
-            "Proof for address {}:",
-            allocation.address
-        )
-        .expect("Failed to write proof header");
+        writeln!(output_file, "Proof for address {}:", allocation.address)
+            .expect("Failed to write proof header");
 
         for p in proof {
-            writeln!(output_file_second, "{}", p).expect("Failed to write proof");
+            writeln!(output_file, "{}", p).expect("Failed to write proof");
         }
-        writeln!(output_file_second).expect("Failed to write newline");
+        writeln!(output_file).expect("Failed to write newline");
     }
+}
 
-    println!("Second Merkle tree data saved in tests/second_merkle_tree_data.txt");
+/// Writes the `BuildManifest` of a tree, so its root can be checked with `reproduce`.
+fn write_manifest(path: &str, tree: &MerkleTree) {
+    let manifest = BuildManifest::for_tree(tree).expect("Failed to describe tree");
+    let json = serde_json::to_string_pretty(&manifest).expect("Failed to serialize manifest");
+    fs::write(path, json + "\n").expect("Failed to write manifest");
 }
@@ -0,0 +1,138 @@
+mod common;
+
+use common::sample_allocations;
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::proof::{
+    build_leaf_levels, compute_root, encode_calldata, hash_pair, leaf_positions, parse_proof,
+    proof_from_levels, verify_proof,
+};
+use starknet::core::types::Felt;
+use starknet_crypto::pedersen_hash;
+
+#[test]
+fn test_hash_pair_is_order_independent() {
+    let a = Felt::from_hex("0x1").unwrap();
+    let b = Felt::from_hex("0x2").unwrap();
+
+    assert_eq!(hash_pair(&a, &b), hash_pair(&b, &a));
+    assert_eq!(hash_pair(&a, &b), pedersen_hash(&a, &b));
+}
+
+#[test]
+fn test_every_calldata_proof_verifies() {
+    let allocations = sample_allocations();
+    let tree = MerkleTree::new(allocations.clone());
+
+    for allocation in allocations.iter() {
+        let calldata = tree
+            .build_address_calldata(
+                &allocation.address,
+                allocation.amount,
+                &allocation.timestamp,
+                allocation.id,
+            )
+            .unwrap();
+        let proof = parse_proof(&calldata[4..]).unwrap();
+        let leaf = allocation.leaf_hash().unwrap();
+
+        assert_eq!(compute_root(leaf, &proof), tree.root.value);
+        assert!(verify_proof(tree.root.value, leaf, &proof));
+    }
+}
+
+#[test]
+fn test_tampered_proof_fails() {
+    let allocations = sample_allocations();
+    let tree = MerkleTree::new(allocations.clone());
+    let calldata = tree
+        .build_address_calldata(
+            &allocations[0].address,
+            allocations[0].amount,
+            &allocations[0].timestamp,
+            allocations[0].id,
+        )
+        .unwrap();
+
+    let mut proof = parse_proof(&calldata[4..]).unwrap();
+    proof[0] += Felt::ONE;
+    let leaf = allocations[0].leaf_hash().unwrap();
+    assert!(!verify_proof(tree.root.value, leaf, &proof));
+
+    let other_leaf = allocations[1].leaf_hash().unwrap();
+    let proof = parse_proof(&calldata[4..]).unwrap();
+    assert!(!verify_proof(tree.root.value, other_leaf, &proof));
+}
+
+#[test]
+fn test_parse_proof_rejects_invalid_hash() {
+    let proof = vec!["0x1".to_string(), "0xnothex".to_string()];
+    assert_eq!(
+        parse_proof(&proof).unwrap_err(),
+        "Invalid proof hash: 0xnothex"
+    );
+}
+
+#[test]
+fn test_levels_match_merkle_tree() {
+    let allocations: Vec<Allocation> = (0..9)
+        .map(|i| Allocation {
+            address: format!("{:#x}", 0x1000 + i),
+            amount: 100,
+            timestamp: "0x1".to_string(),
+            id: i,
+        })
+        .collect();
+
+    for count in 1..=allocations.len() {
+        let tree = MerkleTree::new(allocations[..count].to_vec());
+        let leaves: Vec<Felt> = allocations[..count]
+            .iter()
+            .map(|allocation| allocation.leaf_hash().unwrap())
+            .collect();
+
+        let levels = build_leaf_levels(leaves);
+        assert_eq!(levels.last().unwrap()[0], tree.root.value);
+        for (i, allocation) in allocations[..count].iter().enumerate() {
+            let calldata = tree
+                .build_address_calldata(
+                    &allocation.address,
+                    allocation.amount,
+                    &allocation.timestamp,
+                    allocation.id,
+                )
+                .unwrap();
+            let proof = parse_proof(&calldata[4..]).unwrap();
+            assert_eq!(proof_from_levels(&levels, i), proof);
+        }
+    }
+}
+
+#[test]
+fn test_leaf_positions_keep_first_occurrence() {
+    let positions = leaf_positions(&["a", "b", "a"]);
+
+    assert_eq!(positions["a"], 0);
+    assert_eq!(positions["b"], 1);
+    assert_eq!(positions.len(), 2);
+}
+
+#[test]
+fn test_encode_calldata_prefixes_proof_length() {
+    let proof = vec![Felt::from(7u64), Felt::from(8u64)];
+
+    assert_eq!(
+        encode_calldata(&[Felt::ONE, Felt::TWO], proof),
+        vec![
+            Felt::ONE,
+            Felt::TWO,
+            Felt::TWO,
+            Felt::from(7u64),
+            Felt::from(8u64)
+        ]
+    );
+    assert_eq!(
+        encode_calldata(&[Felt::ONE], vec![]),
+        vec![Felt::ONE, Felt::ZERO]
+    );
+}
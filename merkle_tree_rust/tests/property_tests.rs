@@ -0,0 +1,156 @@
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::proof::{build_leaf_levels, parse_proof, verify_proof};
+use merkle_tree::core::sharded::{ShardStrategy, ShardedMerkleTree};
+use merkle_tree::core::stream::stream_root;
+use proptest::prelude::*;
+use starknet::core::types::Felt;
+use std::collections::HashSet;
+
+/// Felts below 2^251, the range of Starknet addresses.
+fn felt_strategy() -> impl Strategy<Value = Felt> {
+    (any::<u128>(), any::<u128>()).prop_map(|(high, low)| {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&high.to_be_bytes());
+        bytes[16..].copy_from_slice(&low.to_be_bytes());
+        bytes[0] &= 0x07;
+        Felt::from_bytes_be(&bytes)
+    })
+}
+
+fn allocation_strategy() -> impl Strategy<Value = Allocation> {
+    (felt_strategy(), any::<u64>(), any::<u128>(), any::<u64>()).prop_map(
+        |(address, amount, timestamp, id)| Allocation {
+            address: format!("{:#x}", address),
+            amount,
+            timestamp: format!("{:#x}", timestamp),
+            id,
+        },
+    )
+}
+
+/// Distinct allocations, in a random order.
+fn allocations_strategy(max: usize) -> impl Strategy<Value = Vec<Allocation>> {
+    prop::collection::hash_set(allocation_strategy(), 1..max)
+        .prop_map(|allocations| allocations.into_iter().collect::<Vec<_>>())
+        .prop_shuffle()
+}
+
+fn proof_of(tree: &MerkleTree, allocation: &Allocation) -> Vec<Felt> {
+    let calldata = tree
+        .build_address_calldata(
+            &allocation.address,
+            allocation.amount,
+            &allocation.timestamp,
+            allocation.id,
+        )
+        .unwrap();
+    parse_proof(&calldata[4..]).unwrap()
+}
+
+/// Returns a copy of an allocation with one field changed by a non-zero delta.
+fn tamper(allocation: &Allocation, field: usize, delta: u64) -> Allocation {
+    let mut tampered = allocation.clone();
+    match field {
+        0 => {
+            let address = Felt::from_hex(&allocation.address).unwrap();
+            tampered.address = format!("{:#x}", address + Felt::from(delta));
+        }
+        1 => tampered.amount = allocation.amount.wrapping_add(delta),
+        2 => {
+            let timestamp = u128::from_str_radix(&allocation.timestamp[2..], 16).unwrap();
+            tampered.timestamp = format!("{:#x}", timestamp.wrapping_add(delta as u128));
+        }
+        _ => tampered.id = allocation.id.wrapping_add(delta),
+    }
+    tampered
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn every_proof_verifies(allocations in allocations_strategy(24)) {
+        let tree = MerkleTree::new(allocations.clone());
+
+        for allocation in allocations.iter() {
+            let proof = proof_of(&tree, allocation);
+            prop_assert!(verify_proof(tree.root.value, allocation.leaf_hash().unwrap(), &proof));
+        }
+    }
+
+    #[test]
+    fn tampered_leaf_breaks_proof(
+        allocations in allocations_strategy(16),
+        index in any::<prop::sample::Index>(),
+        field in 0..4usize,
+        delta in 1..u64::MAX,
+    ) {
+        let tree = MerkleTree::new(allocations.clone());
+        let allocation = index.get(&allocations);
+        let proof = proof_of(&tree, allocation);
+
+        let tampered = tamper(allocation, field, delta);
+        prop_assert_ne!(&tampered, allocation);
+        prop_assert!(!verify_proof(tree.root.value, tampered.leaf_hash().unwrap(), &proof));
+    }
+
+    #[test]
+    fn root_is_independent_of_build_strategy(allocations in allocations_strategy(24)) {
+        let root = MerkleTree::new(allocations.clone()).root.value;
+
+        prop_assert_eq!(stream_root(allocations.clone()).unwrap(), root);
+
+        let single_shard = ShardStrategy::IndexRange { size: allocations.len() };
+        let sharded = ShardedMerkleTree::new(allocations.clone(), single_shard).unwrap();
+        prop_assert_eq!(sharded.root(), root);
+
+        let leaves: Vec<Felt> = allocations.iter().map(|a| a.leaf_hash().unwrap()).collect();
+        prop_assert_eq!(build_leaf_levels(leaves).last().unwrap()[0], root);
+    }
+
+    #[test]
+    fn merge_matches_fresh_build(
+        allocations in allocations_strategy(24),
+        split in any::<prop::sample::Index>(),
+    ) {
+        // Keep at least one allocation in the first tree
+        let split = 1 + split.index(allocations.len());
+        let (first, second) = allocations.split_at(split);
+
+        let merged = MerkleTree::new(first.to_vec()).merge_merkle_trees(second.to_vec());
+        let fresh = MerkleTree::new(allocations.clone());
+
+        prop_assert_eq!(merged.root.value, fresh.root.value);
+        for allocation in allocations.iter() {
+            prop_assert_eq!(proof_of(&merged, allocation), proof_of(&fresh, allocation));
+        }
+    }
+
+    #[test]
+    fn serialized_tree_round_trips(allocations in allocations_strategy(16)) {
+        let tree = MerkleTree::new(allocations.clone());
+        let json = serde_json::to_string(&tree).unwrap();
+        let loaded: MerkleTree = serde_json::from_str(&json).unwrap();
+
+        prop_assert_eq!(loaded.root.value, tree.root.value);
+        prop_assert_eq!(loaded.get_allocations(), tree.get_allocations());
+    }
+
+    #[test]
+    fn proofs_round_trip_through_hex(allocations in allocations_strategy(16)) {
+        let tree = MerkleTree::new(allocations.clone());
+        let addresses: HashSet<&str> = allocations.iter().map(|a| a.address.as_str()).collect();
+
+        for address in addresses {
+            for calldata in tree.proofs_for(address).unwrap() {
+                let hashes: Vec<String> = parse_proof(&calldata[4..])
+                    .unwrap()
+                    .iter()
+                    .map(|hash| format!("{:#x}", hash))
+                    .collect();
+                prop_assert_eq!(&hashes[..], &calldata[4..]);
+            }
+        }
+    }
+}
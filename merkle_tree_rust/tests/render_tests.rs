@@ -0,0 +1,97 @@
+mod common;
+
+use common::sample_allocations;
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::proof::parse_proof;
+use starknet::core::types::Felt;
+
+fn proof_of(tree: &MerkleTree, allocation: &Allocation) -> Vec<Felt> {
+    let calldata = tree
+        .build_address_calldata(
+            &allocation.address,
+            allocation.amount,
+            &allocation.timestamp,
+            allocation.id,
+        )
+        .unwrap();
+    parse_proof(&calldata[4..]).unwrap()
+}
+
+/// First six hex digits of a hash, as shown in truncated labels.
+fn hash_prefix(hash: &Felt) -> String {
+    format!("0x{}", &format!("{:x}", hash)[..6])
+}
+
+#[test]
+fn test_ascii_lists_every_node() {
+    let tree = MerkleTree::new(sample_allocations());
+    let ascii = tree.to_ascii();
+    let lines: Vec<&str> = ascii.lines().collect();
+
+    // Four leaves, the third duplicated, two inner nodes and the root
+    assert_eq!(lines.len(), 7);
+    assert!(lines[0].starts_with(&format!("root {}", hash_prefix(&tree.root.value))));
+    assert_eq!(ascii.matches("leaf ").count(), 4);
+    assert_eq!(ascii.matches("├── min").count(), 3);
+    assert_eq!(ascii.matches("└── max").count(), 3);
+    assert!(ascii.contains("amount=150 timestamp=0x2 id=1"));
+    // The padded leaf is hashed with its own copy
+    assert_eq!(ascii.matches("(self-paired)").count(), 1);
+}
+
+#[test]
+fn test_ascii_highlights_proof() {
+    let allocations = sample_allocations();
+    let tree = MerkleTree::new(allocations.clone());
+
+    for allocation in allocations.iter() {
+        let ascii = tree.to_ascii_highlighting(allocation).unwrap();
+        let proof = proof_of(&tree, allocation);
+
+        assert_eq!(ascii.matches("<- path").count(), proof.len() + 1);
+        for (i, hash) in proof.iter().enumerate() {
+            let line = ascii
+                .lines()
+                .find(|line| line.ends_with(&format!("<- proof[{}]", i)))
+                .unwrap();
+            assert!(line.contains(&hash_prefix(hash)), "{}", line);
+        }
+    }
+}
+
+#[test]
+fn test_dot_output() {
+    let allocations = sample_allocations();
+    let tree = MerkleTree::new(allocations.clone());
+
+    let dot = tree.to_dot();
+    assert!(dot.starts_with("digraph merkle_tree {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert_eq!(dot.matches(" -> ").count(), 6);
+    assert_eq!(dot.matches("[label=\"min\"]").count(), 3);
+    assert!(!dot.contains("fillcolor"));
+
+    let dot = tree.to_dot_highlighting(&allocations[1]).unwrap();
+    let proof = proof_of(&tree, &allocations[1]);
+    assert_eq!(dot.matches("fillcolor=\"orange\"").count(), proof.len());
+    assert_eq!(
+        dot.matches("fillcolor=\"lightblue\"").count(),
+        proof.len() + 1
+    );
+    assert!(dot.contains(&format!("proof[{}]", proof.len() - 1)));
+}
+
+#[test]
+fn test_highlighting_unknown_allocation_fails() {
+    let tree = MerkleTree::new(sample_allocations());
+    let unknown = Allocation {
+        address: "0x999".to_string(),
+        amount: 1,
+        timestamp: "0x1".to_string(),
+        id: 1,
+    };
+
+    assert!(tree.to_ascii_highlighting(&unknown).is_err());
+    assert!(tree.to_dot_highlighting(&unknown).is_err());
+}
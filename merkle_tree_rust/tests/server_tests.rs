@@ -0,0 +1,190 @@
+#![cfg(feature = "server")]
+
+mod common;
+
+use common::load_mock_data;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::server::ProofServer;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+
+fn start_server() -> (SocketAddr, MerkleTree) {
+    let mut campaigns = BTreeMap::new();
+    campaigns.insert(
+        "first_wave".to_string(),
+        MerkleTree::new(load_mock_data("mock_allocations_first_wave.json")),
+    );
+    campaigns.insert(
+        "second_wave".to_string(),
+        MerkleTree::new(load_mock_data("mock_allocations_second_wave.json")),
+    );
+
+    let server = ProofServer::new(campaigns)
+        .bind("127.0.0.1:0")
+        .expect("Failed to bind server");
+    let addr = server.local_addr().expect("Server should listen on TCP");
+    thread::spawn(move || server.run());
+
+    let reference = MerkleTree::new(load_mock_data("mock_allocations_first_wave.json"));
+    (addr, reference)
+}
+
+/// Sends a bare HTTP/1.1 request and returns the status code and JSON body.
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(addr).expect("Failed to connect");
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .expect("Failed to send request");
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .expect("Failed to read response");
+
+    let status = response[9..12].parse().expect("Missing status code");
+    let (_, body) = response
+        .split_once("\r\n\r\n")
+        .expect("Missing response body");
+    (
+        status,
+        serde_json::from_str(body).expect("Body is not JSON"),
+    )
+}
+
+#[test]
+fn test_get_roots() {
+    let (addr, reference) = start_server();
+    let (status, body) = request(addr, "GET", "/roots", "");
+
+    assert_eq!(status, 200);
+    assert_eq!(
+        body["roots"]["first_wave"],
+        format!("{:#x}", reference.root.value)
+    );
+    assert!(body["roots"]["second_wave"].is_string());
+}
+
+#[test]
+fn test_get_proof_for_address() {
+    let (addr, reference) = start_server();
+    let address = "0xabcdefabcdefabcdefabcdefabcdefabcdef1234";
+    let (status, body) = request(addr, "GET", &format!("/proof/first_wave/{}", address), "");
+
+    assert_eq!(status, 200);
+    let proofs = body["proofs"].as_array().unwrap();
+    let expected = reference.proofs_for(address).unwrap();
+    assert_eq!(proofs.len(), expected.len());
+    for (proof, calldata) in proofs.iter().zip(expected) {
+        assert_eq!(proof["calldata"], json!(calldata));
+        assert_eq!(proof["allocation"]["address"], address);
+    }
+}
+
+#[test]
+fn test_get_proof_errors() {
+    let (addr, _) = start_server();
+
+    let (status, _) = request(addr, "GET", "/proof/unknown/0x1", "");
+    assert_eq!(status, 404);
+
+    let (status, body) = request(addr, "GET", "/proof/first_wave/0xnothex", "");
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "Invalid address");
+
+    let (status, _) = request(addr, "GET", "/proof/first_wave/0xdead", "");
+    assert_eq!(status, 404);
+
+    let (status, _) = request(addr, "DELETE", "/roots", "");
+    assert_eq!(status, 405);
+}
+
+#[test]
+fn test_post_verify() {
+    let (addr, reference) = start_server();
+    let allocation = &reference.get_allocations()[0];
+    let calldata = reference
+        .build_address_calldata(
+            &allocation.address,
+            allocation.amount,
+            &allocation.timestamp,
+            allocation.id,
+        )
+        .unwrap();
+
+    let body = json!({
+        "campaign": "first_wave",
+        "allocation": allocation,
+        "proof": calldata[4..],
+    });
+    let (status, response) = request(addr, "POST", "/verify", &body.to_string());
+    assert_eq!(status, 200);
+    assert_eq!(response["valid"], true);
+
+    // The proof does not belong to the second wave root
+    let body = json!({
+        "campaign": "second_wave",
+        "allocation": allocation,
+        "proof": calldata[4..],
+    });
+    let (status, response) = request(addr, "POST", "/verify", &body.to_string());
+    assert_eq!(status, 200);
+    assert_eq!(response["valid"], false);
+}
+
+#[test]
+fn test_post_verify_validation() {
+    let (addr, _) = start_server();
+
+    let (status, _) = request(addr, "POST", "/verify", "not json");
+    assert_eq!(status, 400);
+
+    let body = json!({
+        "campaign": "first_wave",
+        "allocation": {"address": "0x1", "amount": 1, "timestamp": "0x1", "id": 1},
+        "proof": ["0xnothex"],
+    });
+    let (status, response) = request(addr, "POST", "/verify", &body.to_string());
+    assert_eq!(status, 400);
+    assert_eq!(response["error"], "Invalid proof hash: 0xnothex");
+
+    let body = json!({
+        "campaign": "first_wave",
+        "allocation": {"address": "0xzz", "amount": 1, "timestamp": "0x1", "id": 1},
+        "proof": [],
+    });
+    let (status, response) = request(addr, "POST", "/verify", &body.to_string());
+    assert_eq!(status, 400);
+    assert_eq!(response["error"], "Invalid address");
+}
+
+#[test]
+fn test_load_campaign_dir() {
+    let dir = std::env::temp_dir().join(format!("proof_server_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let tree = MerkleTree::new(load_mock_data("mock_allocations_second_wave.json"));
+    fs::write(
+        dir.join("second_wave.json"),
+        serde_json::to_string(&tree).unwrap(),
+    )
+    .unwrap();
+
+    let proofs = ProofServer::load_dir(&dir).expect("Failed to load campaigns");
+    let (status, body) = proofs.handle("GET", "/roots", "");
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(status, 200);
+    assert_eq!(
+        body["roots"]["second_wave"],
+        format!("{:#x}", tree.root.value)
+    );
+}
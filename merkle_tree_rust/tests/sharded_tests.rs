@@ -0,0 +1,179 @@
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::proof::{build_levels, compute_root, parse_proof, verify_proof};
+use merkle_tree::core::sharded::{ShardStrategy, ShardedMerkleTree};
+use std::fs;
+use std::path::PathBuf;
+
+/// Full-width addresses whose first hex digit cycles through `0x0`..`0x7`.
+fn allocations(count: u64) -> Vec<Allocation> {
+    (0..count)
+        .map(|i| Allocation {
+            address: format!("0x{:x}{:062x}", i % 8, i + 1),
+            amount: 100 + i,
+            timestamp: format!("{:#x}", 1700000000 + i),
+            id: i,
+        })
+        .collect()
+}
+
+fn spill_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("sharded_{}_{}", name, std::process::id()))
+}
+
+fn assert_every_proof_verifies(tree: &ShardedMerkleTree, allocations: &[Allocation]) {
+    for allocation in allocations.iter() {
+        let proof = tree.build_proof(allocation).unwrap();
+        let leaf = allocation.leaf_hash().unwrap();
+        assert_eq!(compute_root(leaf, &proof), tree.root());
+    }
+}
+
+#[test]
+fn test_single_shard_matches_merkle_tree() {
+    let allocations = allocations(5);
+    let tree =
+        ShardedMerkleTree::new(allocations.clone(), ShardStrategy::IndexRange { size: 5 }).unwrap();
+
+    assert_eq!(tree.shards().len(), 1);
+    assert_eq!(tree.root(), MerkleTree::new(allocations).root.value);
+}
+
+#[test]
+fn test_index_range_shards() {
+    let allocations = allocations(10);
+    let dir = spill_dir("index_range");
+    let tree = ShardedMerkleTree::with_spill(
+        allocations.clone(),
+        ShardStrategy::IndexRange { size: 3 },
+        &dir,
+    )
+    .unwrap();
+
+    let sizes: Vec<usize> = tree.shards().iter().map(|shard| shard.leaf_count).collect();
+    assert_eq!(sizes, vec![3, 3, 3, 1]);
+
+    // Each shard is a standalone tree and the root combines their roots
+    for (shard, chunk) in tree.shards().iter().zip(allocations.chunks(3)) {
+        assert_eq!(shard.root(), MerkleTree::new(chunk.to_vec()).root.value);
+    }
+    let roots = tree.shards().iter().map(|shard| shard.root()).collect();
+    assert_eq!(build_levels(roots).last().unwrap()[0], tree.root());
+
+    assert_every_proof_verifies(&tree, &allocations);
+
+    // Input index i is leaf i % size of shard i / size
+    assert_eq!(tree.locate(&allocations[7]).unwrap(), (2, 1));
+    assert_eq!(
+        tree.build_proof_at(2, 1).unwrap(),
+        tree.build_proof(&allocations[7]).unwrap()
+    );
+    assert!(tree.build_proof_at(3, 1).is_err());
+    assert!(tree.build_proof_at(4, 0).is_err());
+
+    // The shard of an input index is computed without reading the shards
+    assert_eq!(tree.locate_index(7).unwrap(), (2, 1));
+    assert_eq!(tree.locate_index(9).unwrap(), (3, 0));
+    assert!(tree.locate_index(10).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_address_prefix_shards() {
+    let allocations = allocations(12);
+    let dir = spill_dir("address_prefix");
+    let tree = ShardedMerkleTree::with_spill(
+        allocations.clone(),
+        ShardStrategy::AddressPrefix { bits: 3 },
+        &dir,
+    )
+    .unwrap();
+
+    // Bit 251 is always zero, so the three top bits are the first hex digit halved
+    let keys: Vec<usize> = tree.shards().iter().map(|shard| shard.key).collect();
+    assert_eq!(keys, vec![0, 1, 2, 3]);
+    for shard in tree.shards() {
+        for allocation in shard.get_allocations().unwrap() {
+            let digit = usize::from_str_radix(&allocation.address[2..3], 16).unwrap();
+            assert_eq!(digit / 2, shard.key);
+        }
+    }
+
+    assert_every_proof_verifies(&tree, &allocations);
+    assert_eq!(
+        tree.locate_index(0),
+        Err("Allocations are not sharded by index".to_string())
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_calldata_proof_verifies() {
+    let allocations = allocations(7);
+    let dir = spill_dir("calldata");
+    let tree = ShardedMerkleTree::with_spill(
+        allocations.clone(),
+        ShardStrategy::IndexRange { size: 2 },
+        &dir,
+    )
+    .unwrap();
+    let allocation = &allocations[6];
+
+    let calldata = tree
+        .build_address_calldata(
+            &allocation.address,
+            allocation.amount,
+            &allocation.timestamp,
+            allocation.id,
+        )
+        .unwrap();
+    let proof = parse_proof(&calldata[4..]).unwrap();
+
+    assert!(verify_proof(
+        tree.root(),
+        allocation.leaf_hash().unwrap(),
+        &proof
+    ));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_invalid_inputs() {
+    assert!(ShardedMerkleTree::new(vec![], ShardStrategy::IndexRange { size: 2 }).is_err());
+    assert!(ShardedMerkleTree::new(allocations(3), ShardStrategy::IndexRange { size: 0 }).is_err());
+    assert!(
+        ShardedMerkleTree::new(allocations(3), ShardStrategy::AddressPrefix { bits: 0 }).is_err()
+    );
+
+    let dir = spill_dir("invalid");
+    let tree =
+        ShardedMerkleTree::with_spill(allocations(3), ShardStrategy::IndexRange { size: 2 }, &dir)
+            .unwrap();
+    let missing = &allocations(4)[3];
+    assert_eq!(
+        tree.build_proof(missing),
+        Err("Allocation not found".to_string())
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_proofs_need_spilled_shards() {
+    let allocations = allocations(4);
+    let tree =
+        ShardedMerkleTree::new(allocations.clone(), ShardStrategy::IndexRange { size: 2 }).unwrap();
+
+    assert_eq!(tree.shards()[1].leaf_count, 2);
+    let not_spilled =
+        "Sharded tree was built without spilling; build it with with_spill to get proofs";
+    assert_eq!(
+        tree.build_proof(&allocations[0]),
+        Err(not_spilled.to_string())
+    );
+    assert_eq!(tree.build_proof_at(0, 0), Err(not_spilled.to_string()));
+    assert!(tree.shards()[0].get_allocations().is_err());
+}
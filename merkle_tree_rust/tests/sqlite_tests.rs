@@ -0,0 +1,125 @@
+#![cfg(feature = "sqlite")]
+
+mod common;
+
+use common::load_mock_data;
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::proof::{parse_proof, verify_proof};
+use merkle_tree::sqlite::ProofStore;
+use std::fs;
+
+#[test]
+fn test_stored_proofs_match_tree() {
+    let tree = MerkleTree::new(load_mock_data("mock_allocations_second_wave.json"));
+    let mut store = ProofStore::open_in_memory().unwrap();
+    store.store_tree("second_wave", &tree).unwrap();
+
+    assert_eq!(
+        store.root("second_wave").unwrap(),
+        Some(format!("{:#x}", tree.root.value))
+    );
+    for allocation in tree.get_allocations() {
+        assert_eq!(
+            store
+                .proofs_for("second_wave", &allocation.address)
+                .unwrap(),
+            tree.proofs_for(&allocation.address).unwrap()
+        );
+
+        let proof = store.proof("second_wave", allocation).unwrap();
+        let leaf = allocation.leaf_hash().unwrap();
+        assert!(verify_proof(tree.root.value, leaf, &proof));
+    }
+}
+
+#[test]
+fn test_store_persists_across_connections() {
+    let path = std::env::temp_dir().join(format!("proof_store_{}.db", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let first_wave = MerkleTree::new(load_mock_data("mock_allocations_first_wave.json"));
+    let second_wave = MerkleTree::new(load_mock_data("mock_allocations_second_wave.json"));
+
+    {
+        let mut store = ProofStore::open(&path).unwrap();
+        store.store_tree("first_wave", &first_wave).unwrap();
+        store.store_tree("second_wave", &first_wave).unwrap();
+        // Storing a campaign again replaces it
+        store.store_tree("second_wave", &second_wave).unwrap();
+    }
+
+    let store = ProofStore::open(&path).unwrap();
+    let roots = store.roots().unwrap();
+    assert_eq!(roots.len(), 2);
+    assert_eq!(roots["first_wave"], format!("{:#x}", first_wave.root.value));
+    assert_eq!(
+        roots["second_wave"],
+        format!("{:#x}", second_wave.root.value)
+    );
+
+    let allocation = &second_wave.get_allocations()[0];
+    let calldata = store
+        .proofs_for("second_wave", &allocation.address)
+        .unwrap()
+        .remove(0);
+    let proof = parse_proof(&calldata[4..]).unwrap();
+    assert!(verify_proof(
+        second_wave.root.value,
+        allocation.leaf_hash().unwrap(),
+        &proof
+    ));
+
+    drop(store);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_allocations_round_trip_large_values() {
+    let allocation = Allocation {
+        address: "0x0ABC".to_string(),
+        amount: u64::MAX,
+        timestamp: "0x5".to_string(),
+        id: u64::MAX,
+    };
+    let tree = MerkleTree::new(vec![allocation.clone()]);
+    let mut store = ProofStore::open_in_memory().unwrap();
+    store.store_tree("large", &tree).unwrap();
+
+    // Addresses are matched by value, not by spelling
+    assert_eq!(
+        store.allocations_for("large", "0xabc").unwrap(),
+        vec![allocation.clone()]
+    );
+    assert_eq!(
+        store.proof("large", &allocation).unwrap(),
+        vec![allocation.leaf_hash().unwrap()]
+    );
+}
+
+#[test]
+fn test_missing_campaign_and_allocation() {
+    let tree = MerkleTree::new(load_mock_data("mock_allocations_first_wave.json"));
+    let mut store = ProofStore::open_in_memory().unwrap();
+    store.store_tree("first_wave", &tree).unwrap();
+
+    let allocation = Allocation {
+        address: "0x999".to_string(),
+        amount: 1,
+        timestamp: "0x1".to_string(),
+        id: 1,
+    };
+    assert_eq!(store.root("unknown").unwrap(), None);
+    assert_eq!(
+        store.proof("unknown", &allocation),
+        Err("Campaign unknown not found".to_string())
+    );
+    assert_eq!(
+        store.proof("first_wave", &allocation),
+        Err("Allocation not found".to_string())
+    );
+    assert!(store
+        .allocations_for("first_wave", "0x999")
+        .unwrap()
+        .is_empty());
+    assert!(store.allocations_for("first_wave", "invalid").is_err());
+}
@@ -0,0 +1,227 @@
+mod common;
+
+use common::generated_allocations;
+use merkle_tree::core::merkle_tree::{LeafOrdering, MerkleTree};
+use merkle_tree::core::proof::{hash_pair, parse_proof};
+use merkle_tree::core::standard_tree::{StandardMerkleTreeDump, LEAF_ENCODING, STANDARD_FORMAT};
+use starknet::core::types::Felt;
+
+fn heap(dump: &StandardMerkleTreeDump) -> Vec<Felt> {
+    dump.tree
+        .iter()
+        .map(|hash| Felt::from_hex(hash).unwrap())
+        .collect()
+}
+
+/// Walks the heap from a leaf to the root, as OpenZeppelin's `getProof` does.
+fn heap_proof(tree: &[Felt], mut index: usize) -> Vec<Felt> {
+    let mut proof = vec![];
+    while index > 0 {
+        let sibling = if index % 2 == 1 { index + 1 } else { index - 1 };
+        proof.push(tree[sibling]);
+        index = (index - 1) / 2;
+    }
+    proof
+}
+
+#[test]
+fn test_dump_layout() {
+    let tree = MerkleTree::new(generated_allocations(5));
+    let dump = tree.to_standard_dump().unwrap();
+    let json = serde_json::to_value(&dump).unwrap();
+
+    assert_eq!(json["format"], STANDARD_FORMAT);
+    assert_eq!(json["leafEncoding"], serde_json::json!(LEAF_ENCODING));
+    assert_eq!(json["values"][0]["value"][1], "100");
+    assert!(json["values"][0]["treeIndex"].is_u64());
+    assert_eq!(dump.values.len(), 5);
+    assert_eq!(Felt::from_hex(&dump.tree[0]).unwrap(), tree.root.value);
+}
+
+#[test]
+fn test_every_node_hashes_its_children() {
+    for count in 1..=9 {
+        let dump = MerkleTree::new(generated_allocations(count))
+            .to_standard_dump()
+            .unwrap();
+        let tree = heap(&dump);
+
+        for i in 0..tree.len() {
+            if 2 * i + 2 < tree.len() {
+                assert_eq!(tree[i], hash_pair(&tree[2 * i + 1], &tree[2 * i + 2]));
+            }
+        }
+        for value in dump.values.iter() {
+            assert!(2 * value.tree_index + 1 >= tree.len());
+        }
+    }
+}
+
+#[test]
+fn test_heap_proofs_match_calldata() {
+    for count in 1..=9 {
+        let tree = MerkleTree::new(generated_allocations(count));
+        let dump = tree.to_standard_dump().unwrap();
+        let nodes = heap(&dump);
+
+        for (allocation, value) in tree.get_allocations().iter().zip(dump.values.iter()) {
+            let calldata = tree
+                .build_address_calldata(
+                    &allocation.address,
+                    allocation.amount,
+                    &allocation.timestamp,
+                    allocation.id,
+                )
+                .unwrap();
+            assert_eq!(nodes[value.tree_index], allocation.leaf_hash().unwrap());
+            assert_eq!(
+                heap_proof(&nodes, value.tree_index),
+                parse_proof(&calldata[4..]).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_dump_round_trips() {
+    let tree = MerkleTree::new(generated_allocations(7));
+    let json = serde_json::to_string(&tree.to_standard_dump().unwrap()).unwrap();
+
+    let dump: StandardMerkleTreeDump = serde_json::from_str(&json).unwrap();
+    let imported = MerkleTree::from_standard_dump(&dump).unwrap();
+    assert_eq!(imported.root.value, tree.root.value);
+    assert_eq!(imported.get_allocations(), tree.get_allocations());
+}
+
+#[test]
+fn test_round_trip_keeps_value_strings() {
+    let mut allocations = generated_allocations(3);
+    allocations[1].address = "0x3F5A".to_string();
+    allocations[1].timestamp = "0x6553F1A0".to_string();
+    let tree = MerkleTree::new(allocations);
+
+    let dump = tree.to_standard_dump().unwrap();
+    assert_eq!(dump.values[1].value[0], "0x3F5A");
+    assert_eq!(dump.values[1].value[2], "0x6553F1A0");
+
+    let imported = MerkleTree::from_standard_dump(&dump).unwrap();
+    assert_eq!(imported.get_allocations(), tree.get_allocations());
+    assert_eq!(
+        imported
+            .build_address_calldata("0x3F5A", 101, "0x6553F1A0", 1)
+            .unwrap(),
+        tree.build_address_calldata("0x3F5A", 101, "0x6553F1A0", 1)
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_round_trip_keeps_ordering() {
+    let tree = MerkleTree::with_ordering(generated_allocations(6), LeafOrdering::LeafHash);
+    let json = serde_json::to_value(tree.to_standard_dump().unwrap()).unwrap();
+    assert_eq!(json["ordering"], "leaf_hash");
+
+    let dump: StandardMerkleTreeDump = serde_json::from_value(json).unwrap();
+    let imported = MerkleTree::from_standard_dump(&dump).unwrap();
+    assert_eq!(imported.ordering(), LeafOrdering::LeafHash);
+    assert_eq!(imported.root.value, tree.root.value);
+}
+
+#[test]
+fn test_import_defaults_to_insertion_ordering() {
+    let mut json = serde_json::to_value(
+        MerkleTree::new(generated_allocations(3))
+            .to_standard_dump()
+            .unwrap(),
+    )
+    .unwrap();
+    json.as_object_mut().unwrap().remove("ordering");
+    json["values"][0]["value"][2] = serde_json::json!("1700000000");
+
+    let dump: StandardMerkleTreeDump = serde_json::from_value(json).unwrap();
+    let imported = MerkleTree::from_standard_dump(&dump).unwrap();
+    assert_eq!(imported.ordering(), LeafOrdering::Insertion);
+    assert_eq!(imported.get_allocations()[0].timestamp, "0x6553f100");
+}
+
+#[test]
+fn test_import_accepts_felt252_encoding() {
+    let mut dump = MerkleTree::new(generated_allocations(3))
+        .to_standard_dump()
+        .unwrap();
+    dump.leaf_encoding = vec!["felt252".to_string(); 4];
+
+    assert!(MerkleTree::from_standard_dump(&dump).is_ok());
+}
+
+#[test]
+fn test_import_rejects_unsupported_dumps() {
+    let dump = MerkleTree::new(generated_allocations(3))
+        .to_standard_dump()
+        .unwrap();
+
+    let mut format = dump.clone();
+    format.format = "simple-v1".to_string();
+    assert_eq!(
+        MerkleTree::from_standard_dump(&format).err().unwrap(),
+        "Unsupported format: simple-v1"
+    );
+
+    let mut encoding = dump.clone();
+    encoding.leaf_encoding = vec!["address".to_string(), "uint256".to_string()];
+    assert_eq!(
+        MerkleTree::from_standard_dump(&encoding).err().unwrap(),
+        "Unsupported leaf encoding: [address, uint256]"
+    );
+
+    let mut amount = dump.clone();
+    amount.values[0].value[1] = "18446744073709551616".to_string();
+    assert_eq!(
+        MerkleTree::from_standard_dump(&amount).err().unwrap(),
+        "Invalid amount"
+    );
+}
+
+#[test]
+fn test_import_rejects_tampered_dumps() {
+    let dump = MerkleTree::new(generated_allocations(4))
+        .to_standard_dump()
+        .unwrap();
+
+    let mut node = dump.clone();
+    node.tree[1] = "0x1".to_string();
+    assert_eq!(
+        MerkleTree::from_standard_dump(&node).err().unwrap(),
+        "Tree does not match values"
+    );
+
+    let mut value = dump.clone();
+    value.values[0].value[1] = "999".to_string();
+    assert_eq!(
+        MerkleTree::from_standard_dump(&value).err().unwrap(),
+        "Tree does not match values"
+    );
+
+    let mut index = dump.clone();
+    index.values.swap(0, 1);
+    index.values[0].tree_index = dump.values[0].tree_index;
+    index.values[1].tree_index = dump.values[1].tree_index;
+    assert_eq!(
+        MerkleTree::from_standard_dump(&index).err().unwrap(),
+        format!("Invalid tree index: {}", dump.values[0].tree_index)
+    );
+}
+
+#[test]
+fn test_export_rejects_timestamps_above_u128() {
+    let mut allocations = generated_allocations(2);
+    allocations[0].timestamp = format!("0x1{}", "0".repeat(32));
+
+    assert_eq!(
+        MerkleTree::new(allocations)
+            .to_standard_dump()
+            .err()
+            .unwrap(),
+        "Timestamp does not fit in u128"
+    );
+}
@@ -0,0 +1,145 @@
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::proof::{compute_root, parse_proof};
+use merkle_tree::core::stream::{
+    read_json_lines, stream_root, SpilledLevels, StreamingTreeBuilder,
+};
+use std::fs;
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn allocations(count: u64) -> Vec<Allocation> {
+    (0..count)
+        .map(|i| Allocation {
+            address: format!("{:#x}", 0x1000 + i),
+            amount: 100 + i,
+            timestamp: "0x1".to_string(),
+            id: i,
+        })
+        .collect()
+}
+
+fn spill_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("stream_{}_{}", name, std::process::id()))
+}
+
+#[test]
+fn test_streamed_root_matches_merkle_tree() {
+    for count in 1..=17 {
+        let allocations = allocations(count);
+        assert_eq!(
+            stream_root(allocations.clone()).unwrap(),
+            MerkleTree::new(allocations).root.value,
+            "{} leaves",
+            count
+        );
+    }
+}
+
+#[test]
+fn test_spilled_proofs_match_merkle_tree() {
+    let dir = spill_dir("proofs");
+
+    for count in [1, 2, 6, 11] {
+        let allocations = allocations(count);
+        let tree = MerkleTree::new(allocations.clone());
+
+        let mut builder = StreamingTreeBuilder::with_spill(&dir).unwrap();
+        for allocation in allocations.iter() {
+            builder.push(allocation).unwrap();
+        }
+        assert_eq!(builder.leaf_count(), count);
+        let root = builder.finalize().unwrap();
+
+        let levels = SpilledLevels::open(&dir).unwrap();
+        assert_eq!(levels.root().unwrap(), root);
+        for (i, allocation) in allocations.iter().enumerate() {
+            let calldata = tree
+                .build_address_calldata(
+                    &allocation.address,
+                    allocation.amount,
+                    &allocation.timestamp,
+                    allocation.id,
+                )
+                .unwrap();
+            let proof = levels.proof(i as u64).unwrap();
+
+            assert_eq!(
+                levels.leaf(i as u64).unwrap(),
+                allocation.leaf_hash().unwrap()
+            );
+            assert_eq!(proof, parse_proof(&calldata[4..]).unwrap());
+            assert_eq!(compute_root(allocation.leaf_hash().unwrap(), &proof), root);
+        }
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_empty_stream_fails() {
+    assert_eq!(
+        stream_root(vec![]),
+        Err("Tree has no allocations".to_string())
+    );
+}
+
+#[test]
+fn test_read_json_lines() {
+    let input = allocations(3)
+        .iter()
+        .map(|allocation| serde_json::to_string(allocation).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let parsed: Vec<Allocation> = read_json_lines(Cursor::new(input))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(parsed, allocations(3));
+
+    let error = read_json_lines(Cursor::new("{\"address\": \"0x1\"}"))
+        .next()
+        .unwrap()
+        .unwrap_err();
+    assert!(error.starts_with("Invalid allocation on line 1"));
+}
+
+#[test]
+fn test_cli_root_and_proof() {
+    let dir = spill_dir("cli");
+    fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("allocations.jsonl");
+    let allocations = allocations(5);
+    let lines: Vec<String> = allocations
+        .iter()
+        .map(|allocation| serde_json::to_string(allocation).unwrap())
+        .collect();
+    fs::write(&input, lines.join("\n")).unwrap();
+
+    let levels = dir.join("levels");
+    let output = Command::new(env!("CARGO_BIN_EXE_merkle_tree_cli"))
+        .args(["root", input.to_str().unwrap(), levels.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let tree = MerkleTree::new(allocations.clone());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap().trim(),
+        format!("{:#x}", tree.root.value)
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_merkle_tree_cli"))
+        .args(["proof", levels.to_str().unwrap(), "4"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let proof: Vec<String> = serde_json::from_slice(&output.stdout).unwrap();
+    let leaf = allocations[4].leaf_hash().unwrap();
+    assert_eq!(
+        compute_root(leaf, &parse_proof(&proof).unwrap()),
+        tree.root.value
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
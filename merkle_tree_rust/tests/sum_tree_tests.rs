@@ -0,0 +1,140 @@
+mod common;
+
+use common::sample_allocations;
+use merkle_tree::core::sum_tree::{
+    compute_sum_root, hash_sum_pair, verify_sum_proof, MerkleSumTree, SumNode,
+};
+use starknet::core::types::Felt;
+
+fn node(hash: &str, sum: u128) -> SumNode {
+    SumNode {
+        hash: Felt::from_hex(hash).unwrap(),
+        sum,
+    }
+}
+
+#[test]
+fn test_root_commits_to_total() {
+    let tree = MerkleSumTree::new(sample_allocations()).unwrap();
+
+    // Same vector as test_sum_root_matches_rust in tests_sum_tree.cairo
+    assert_eq!(
+        tree.root(),
+        node(
+            "0x2968157df6520f4a0156dec378e093edebb31b86a6053ab98ab59d26f7c6627",
+            600
+        )
+    );
+}
+
+#[test]
+fn test_proofs_carry_subtotals() {
+    let allocations = sample_allocations();
+    let tree = MerkleSumTree::new(allocations.clone()).unwrap();
+
+    // The last leaf of an odd level is paired with an empty node
+    let proof = tree.build_proof(&allocations[2]).unwrap();
+    assert_eq!(proof[0], SumNode::EMPTY);
+    assert_eq!(proof[1].sum, 350);
+
+    for allocation in allocations.iter() {
+        let leaf = SumNode::new_leaf(allocation).unwrap();
+        let proof = tree.build_proof(allocation).unwrap();
+        assert!(verify_sum_proof(&tree.root(), leaf, &proof));
+    }
+}
+
+#[test]
+fn test_tampered_subtotal_fails() {
+    let allocations = sample_allocations();
+    let tree = MerkleSumTree::new(allocations.clone()).unwrap();
+    let leaf = SumNode::new_leaf(&allocations[0]).unwrap();
+
+    let mut proof = tree.build_proof(&allocations[0]).unwrap();
+    proof[1].sum -= 1;
+    assert!(!verify_sum_proof(&tree.root(), leaf, &proof));
+
+    // Claiming more than allocated changes the root total
+    let inflated = SumNode {
+        sum: leaf.sum + 1,
+        ..leaf
+    };
+    let proof = tree.build_proof(&allocations[0]).unwrap();
+    assert!(!verify_sum_proof(&tree.root(), inflated, &proof));
+}
+
+#[test]
+fn test_single_allocation() {
+    let allocations = vec![sample_allocations().remove(0)];
+    let tree = MerkleSumTree::new(allocations.clone()).unwrap();
+    let leaf = SumNode::new_leaf(&allocations[0]).unwrap();
+
+    assert_eq!(tree.root().sum, 150);
+    assert_eq!(tree.root(), hash_sum_pair(&leaf, &SumNode::EMPTY).unwrap());
+}
+
+#[test]
+fn test_build_address_calldata() {
+    let allocations = sample_allocations();
+    let tree = MerkleSumTree::new(allocations.clone()).unwrap();
+    let calldata = tree
+        .build_address_calldata(
+            &allocations[1].address,
+            allocations[1].amount,
+            &allocations[1].timestamp,
+            allocations[1].id,
+        )
+        .unwrap();
+
+    assert_eq!(
+        calldata,
+        vec![
+            "0xabcdefabcdefabcdefabcdefabcdefabcdef1234",
+            "0xc8",
+            "0x3",
+            "0x2",
+            "0x1fe08cf79b4dbf5050c2b1697ea059019a18f837d45100bcce43605bc769e58",
+            "0x96",
+            "0x3b3487c618fed779bbd64580b85e4871c6a98250e3ac70f4b7628400ba282b0",
+            "0xfa",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_input() {
+    assert_eq!(
+        MerkleSumTree::new(vec![]).err().unwrap(),
+        "Tree has no allocations"
+    );
+
+    let mut allocations = sample_allocations();
+    allocations[1].address = "0x1234567dhiodhaoo".to_string();
+    assert_eq!(
+        MerkleSumTree::new(allocations).err().unwrap(),
+        "Invalid address"
+    );
+
+    let tree = MerkleSumTree::new(sample_allocations()).unwrap();
+    assert_eq!(
+        tree.build_address_calldata("0xdead", 1, "0x1", 1)
+            .unwrap_err(),
+        "Allocation not found"
+    );
+}
+
+#[test]
+fn test_subtotal_overflow() {
+    let big = SumNode {
+        hash: Felt::ONE,
+        sum: u128::MAX,
+    };
+    let small = SumNode {
+        hash: Felt::TWO,
+        sum: 1,
+    };
+    assert_eq!(
+        compute_sum_root(big, &[small]).unwrap_err(),
+        "Subtotal overflow"
+    );
+}
@@ -0,0 +1,93 @@
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::core::proof::{compute_root, parse_proof};
+use serde::Deserialize;
+use starknet::core::types::Felt;
+use std::fs;
+
+const VECTORS_PATH: &str = "../test_vectors/merkle_tree_v1.json";
+const CAIRO_VECTORS_PATH: &str = "../merkle_tree_cairo/tests/test_vectors.cairo";
+
+#[derive(Deserialize)]
+struct TestVectors {
+    version: u32,
+    cases: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+struct TestCase {
+    name: String,
+    allocations: Vec<Allocation>,
+    leaves: Vec<String>,
+    root: String,
+    proofs: Vec<Vec<String>>,
+}
+
+fn load_vectors() -> TestVectors {
+    let data = fs::read_to_string(VECTORS_PATH).expect("Failed to read test vectors");
+    serde_json::from_str(&data).expect("Failed to parse test vectors")
+}
+
+fn felt(hex: &str) -> Felt {
+    Felt::from_hex(hex).unwrap()
+}
+
+#[test]
+fn test_vectors_version() {
+    assert_eq!(load_vectors().version, 1);
+}
+
+#[test]
+fn test_vectors_match_tree() {
+    for case in load_vectors().cases {
+        let tree = MerkleTree::new(case.allocations.clone());
+        assert_eq!(tree.root.value, felt(&case.root), "{}: root", case.name);
+
+        for (i, allocation) in case.allocations.iter().enumerate() {
+            let leaf = allocation.leaf_hash().unwrap();
+            assert_eq!(leaf, felt(&case.leaves[i]), "{}: leaf {}", case.name, i);
+
+            let calldata = tree
+                .build_address_calldata(
+                    &allocation.address,
+                    allocation.amount,
+                    &allocation.timestamp,
+                    allocation.id,
+                )
+                .unwrap();
+            let proof = parse_proof(&calldata[4..]).unwrap();
+            assert_eq!(
+                proof,
+                parse_proof(&case.proofs[i]).unwrap(),
+                "{}: proof {}",
+                case.name,
+                i
+            );
+            assert_eq!(compute_root(leaf, &proof), tree.root.value);
+        }
+    }
+}
+
+#[test]
+fn test_cairo_vectors_are_generated_from_json() {
+    // Regenerate with `python3 test_vectors/generate_cairo.py test_vectors/merkle_tree_v1.json merkle_tree_cairo/tests/`
+    let cairo = fs::read_to_string(CAIRO_VECTORS_PATH).expect("Failed to read Cairo vectors");
+    let vectors = load_vectors();
+
+    assert!(cairo.contains(&format!("TEST_VECTORS_VERSION: u32 = {};", vectors.version)));
+    for case in vectors.cases {
+        assert!(
+            cairo.contains(&format!("name: '{}',", case.name)),
+            "{}",
+            case.name
+        );
+        assert!(
+            cairo.contains(&format!("root: {},", case.root)),
+            "{}",
+            case.name
+        );
+        for leaf in case.leaves.iter() {
+            assert!(cairo.contains(&format!("leaf: {},", leaf)), "{}", case.name);
+        }
+    }
+}
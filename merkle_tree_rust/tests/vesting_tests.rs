@@ -0,0 +1,199 @@
+use merkle_tree::core::proof::verify_proof;
+use merkle_tree::core::vesting::{VestingSchedule, VestingTree, RELEASE_ENTRYPOINT};
+use starknet::core::types::Felt;
+use starknet::core::utils::get_selector_from_name;
+
+fn sample_schedules() -> Vec<VestingSchedule> {
+    vec![
+        VestingSchedule {
+            beneficiary: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            total: 1200,
+            start: 1000,
+            cliff: 100,
+            duration: 400,
+            id: 1,
+        },
+        VestingSchedule {
+            beneficiary: "0xabcdefabcdefabcdefabcdefabcdefabcdef1234".to_string(),
+            total: 500,
+            start: 1000,
+            cliff: 0,
+            duration: 1000,
+            id: 1,
+        },
+        VestingSchedule {
+            beneficiary: "0x1234567890abcdef1234567890abcdef12345678".to_string(),
+            total: 300,
+            start: 2000,
+            cliff: 0,
+            duration: 0,
+            id: 2,
+        },
+    ]
+}
+
+#[test]
+fn test_vesting_root_matches_cairo() {
+    // Same values as tests_vesting.cairo
+    let tree = VestingTree::new(sample_schedules()).unwrap();
+
+    assert_eq!(
+        sample_schedules()[0].leaf_hash().unwrap(),
+        Felt::from_hex("0x3bcc909343475db2e018850d1d81bf11bcfbb723eaca0e8a406e092a3635774")
+            .unwrap()
+    );
+    assert_eq!(
+        tree.root(),
+        Felt::from_hex("0x1422a55da68895120d32a4426eee46d08360f002f522c7b5d6af8d078fbb35b")
+            .unwrap()
+    );
+    assert_eq!(
+        tree.build_proof(&sample_schedules()[0]).unwrap(),
+        vec![
+            Felt::from_hex("0x7f1fa7441e3135d41f10d0c17c00ce788272fb4a3ae57cb61b2938e43c0d38")
+                .unwrap(),
+            Felt::from_hex("0x17a4bf0e5cbdae059393f21924f6a260315024527f9b6e1c786bbcef5e23851")
+                .unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn test_every_schedule_has_a_valid_proof() {
+    let tree = VestingTree::new(sample_schedules()).unwrap();
+
+    for schedule in tree.get_schedules() {
+        let proof = tree.build_proof(schedule).unwrap();
+        assert!(verify_proof(
+            tree.root(),
+            schedule.leaf_hash().unwrap(),
+            &proof
+        ));
+    }
+
+    let mut unknown = sample_schedules()[0].clone();
+    unknown.total += 1;
+    assert_eq!(
+        tree.build_proof(&unknown).err().unwrap(),
+        "Schedule not found"
+    );
+}
+
+#[test]
+fn test_vested_amount() {
+    let schedule = &sample_schedules()[0];
+
+    assert_eq!(schedule.vested_amount(0), 0);
+    assert_eq!(schedule.vested_amount(1000), 0);
+    assert_eq!(schedule.vested_amount(1099), 0);
+    assert_eq!(schedule.vested_amount(1100), 300);
+    assert_eq!(schedule.vested_amount(1201), 603);
+    assert_eq!(schedule.vested_amount(1400), 1200);
+    assert_eq!(schedule.vested_amount(u64::MAX), 1200);
+}
+
+#[test]
+fn test_vested_amount_without_cliff_or_duration() {
+    let schedules = sample_schedules();
+
+    assert_eq!(schedules[1].vested_amount(999), 0);
+    assert_eq!(schedules[1].vested_amount(1000), 0);
+    assert_eq!(schedules[1].vested_amount(1001), 0);
+    assert_eq!(schedules[1].vested_amount(1002), 1);
+    assert_eq!(schedules[1].vested_amount(1500), 250);
+
+    assert_eq!(schedules[2].vested_amount(1999), 0);
+    assert_eq!(schedules[2].vested_amount(2000), 300);
+}
+
+#[test]
+fn test_vested_amount_does_not_overflow() {
+    let schedule = VestingSchedule {
+        beneficiary: "0x1".to_string(),
+        total: u64::MAX,
+        start: 0,
+        cliff: 0,
+        duration: u64::MAX,
+        id: 1,
+    };
+
+    assert_eq!(schedule.vested_amount(u64::MAX / 2), u64::MAX / 2);
+}
+
+#[test]
+fn test_releasable_amount() {
+    let schedule = &sample_schedules()[0];
+
+    assert_eq!(schedule.releasable_amount(1100, 0), 300);
+    assert_eq!(schedule.releasable_amount(1200, 300), 300);
+    assert_eq!(schedule.releasable_amount(1200, 600), 0);
+    assert_eq!(schedule.releasable_amount(1400, 600), 600);
+}
+
+#[test]
+fn test_build_release_call() {
+    let tree = VestingTree::new(sample_schedules()).unwrap();
+    let call = tree
+        .build_release_call("0x123", &sample_schedules()[0])
+        .unwrap();
+
+    assert_eq!(call.to, Felt::from_hex("0x123").unwrap());
+    assert_eq!(
+        call.selector,
+        get_selector_from_name(RELEASE_ENTRYPOINT).unwrap()
+    );
+    let mut calldata = vec![
+        Felt::from(1200u64),
+        Felt::from(1000u64),
+        Felt::from(100u64),
+        Felt::from(400u64),
+        Felt::ONE,
+        Felt::TWO,
+    ];
+    calldata.extend(tree.build_proof(&sample_schedules()[0]).unwrap());
+    assert_eq!(call.calldata, calldata);
+
+    assert_eq!(
+        tree.build_release_call("not hex", &sample_schedules()[0])
+            .err()
+            .unwrap(),
+        "Invalid contract address"
+    );
+}
+
+#[test]
+fn test_invalid_schedules_are_rejected() {
+    assert_eq!(
+        VestingTree::new(vec![]).err().unwrap(),
+        "Tree has no schedules"
+    );
+
+    let mut schedules = sample_schedules();
+    schedules[1].cliff = 1001;
+    assert_eq!(
+        VestingTree::new(schedules).err().unwrap(),
+        "Cliff is longer than duration"
+    );
+
+    let mut schedules = sample_schedules();
+    schedules[2].beneficiary = "bob".to_string();
+    assert_eq!(
+        VestingTree::new(schedules).err().unwrap(),
+        "Invalid beneficiary"
+    );
+}
+
+#[test]
+fn test_schedule_json() {
+    let json = serde_json::to_value(&sample_schedules()[0]).unwrap();
+
+    assert_eq!(
+        json["beneficiary"],
+        "0x1234567890abcdef1234567890abcdef12345678"
+    );
+    assert_eq!(json["cliff"], 100);
+    assert_eq!(
+        serde_json::from_value::<VestingSchedule>(json).unwrap(),
+        sample_schedules()[0]
+    );
+}
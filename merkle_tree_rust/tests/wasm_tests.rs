@@ -0,0 +1,132 @@
+//! Run with `wasm-pack test --node --features wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+mod common;
+
+use common::sample_allocations;
+use merkle_tree::core::allocation::Allocation;
+use merkle_tree::core::merkle_tree::MerkleTree;
+use merkle_tree::wasm::{leaf_hash, verify, WasmMerkleTree};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+/// `AddressProof` as returned by `proofsFor`.
+#[derive(Deserialize)]
+struct AddressProof {
+    allocation: Allocation,
+    calldata: Vec<String>,
+}
+
+fn to_js<T: serde::Serialize, U: JsCast>(value: &T) -> U {
+    serde_wasm_bindgen::to_value(value)
+        .unwrap()
+        .unchecked_into()
+}
+
+#[wasm_bindgen_test]
+fn test_root_matches_native_tree() {
+    let allocations = sample_allocations();
+    let tree = WasmMerkleTree::new(to_js(&allocations)).unwrap();
+    let native = MerkleTree::new(allocations);
+
+    assert_eq!(tree.root(), format!("{:#x}", native.root.value));
+}
+
+#[wasm_bindgen_test]
+fn test_build_and_verify_calldata() {
+    let allocations = sample_allocations();
+    let tree = WasmMerkleTree::new(to_js(&allocations)).unwrap();
+
+    for allocation in allocations.iter() {
+        let calldata: Vec<String> = serde_wasm_bindgen::from_value(
+            tree.build_address_calldata(
+                &allocation.address,
+                allocation.amount,
+                &allocation.timestamp,
+                allocation.id,
+            )
+            .unwrap()
+            .into(),
+        )
+        .unwrap();
+
+        let valid = verify(
+            &tree.root(),
+            to_js(allocation),
+            to_js(&calldata[4..].to_vec()),
+        )
+        .unwrap();
+        assert!(valid);
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_proofs_for_address() {
+    let mut allocations = sample_allocations();
+    allocations[2].address = allocations[0].address.clone();
+    let tree = WasmMerkleTree::new(to_js(&allocations)).unwrap();
+
+    let proofs: Vec<AddressProof> =
+        serde_wasm_bindgen::from_value(tree.proofs_for(&allocations[0].address).unwrap().into())
+            .unwrap();
+    assert_eq!(proofs.len(), 2);
+    assert_eq!(proofs[1].allocation.amount, 250);
+}
+
+#[wasm_bindgen_test]
+fn test_proofs_for_keeps_large_ids() {
+    let mut allocations = sample_allocations();
+    allocations[0].id = (1 << 53) + 1;
+    allocations[0].amount = u64::MAX;
+    let bigints = serde_wasm_bindgen::Serializer::json_compatible()
+        .serialize_large_number_types_as_bigints(true);
+    let tree =
+        WasmMerkleTree::new(allocations.serialize(&bigints).unwrap().unchecked_into()).unwrap();
+
+    // `json_compatible` alone refuses numbers above `Number.MAX_SAFE_INTEGER`
+    let proofs: Vec<AddressProof> =
+        serde_wasm_bindgen::from_value(tree.proofs_for(&allocations[0].address).unwrap().into())
+            .unwrap();
+    assert_eq!(proofs[0].allocation, allocations[0]);
+    assert_eq!(
+        proofs[0].calldata,
+        MerkleTree::new(allocations.clone())
+            .build_address_calldata(
+                &allocations[0].address,
+                u64::MAX,
+                &allocations[0].timestamp,
+                (1 << 53) + 1,
+            )
+            .unwrap()
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_bigint_amounts_keep_precision() {
+    let mut allocations = sample_allocations();
+    allocations[0].amount = u64::MAX;
+    allocations[0].id = (1 << 53) + 1;
+    let bigints = serde_wasm_bindgen::Serializer::json_compatible()
+        .serialize_large_number_types_as_bigints(true);
+    let js_allocations = allocations.serialize(&bigints).unwrap().unchecked_into();
+
+    let tree = WasmMerkleTree::new(js_allocations).unwrap();
+    assert_eq!(
+        tree.root(),
+        format!("{:#x}", MerkleTree::new(allocations).root.value)
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_invalid_input_is_rejected() {
+    let allocations = vec![Allocation {
+        address: "0x1234567dhiodhaoo".to_string(),
+        amount: 150,
+        timestamp: "0x2".to_string(),
+        id: 1,
+    }];
+    assert!(WasmMerkleTree::new(to_js(&allocations)).is_err());
+    assert!(WasmMerkleTree::new(to_js(&Vec::<Allocation>::new())).is_err());
+    assert!(leaf_hash(to_js(&allocations[0])).is_err());
+}
@@ -0,0 +1,90 @@
+import json
+import os
+import sys
+
+# Writes merkle_tree_cairo/tests/test_vectors.cairo from a JSON test-vector file.
+# Usage, from the project root:
+#   python3 test_vectors/generate_cairo.py test_vectors/merkle_tree_v1.json merkle_tree_cairo/tests/
+
+running_path = os.getcwd()
+arguments = sys.argv
+if len(arguments) != 3:
+    print("Usage: python3 generate_cairo.py <vectors.json> <cairo_test_folder>")
+    raise SystemExit(1)
+
+vectors_path = f"{running_path}/{arguments[1]}"
+output_path = f"{running_path}/{arguments[2]}/test_vectors.cairo"
+
+try:
+    with open(vectors_path) as vectors_file:
+        vectors = json.load(vectors_file)
+except Exception as e:
+    print(e)
+    raise SystemExit(1)
+
+
+def leaf_code(allocation, leaf, proof):
+    proof_items = ",\n".join(f"                        {hash}" for hash in proof)
+    return f"""                VectorLeaf {{
+                    claimee: {allocation['address'].lower()},
+                    amount: {allocation['amount']},
+                    timestamp: {int(allocation['timestamp'], 16)},
+                    id: {allocation['id']},
+                    leaf: {leaf},
+                    proof: array![
+{proof_items}
+                    ]
+                }}"""
+
+
+def case_code(case):
+    leaves = ",\n".join(
+        leaf_code(allocation, leaf, proof)
+        for allocation, leaf, proof in zip(case["allocations"], case["leaves"], case["proofs"])
+    )
+    return f"""        TestVector {{
+            name: '{case['name']}',
+            root: {case['root']},
+            leaves: array![
+{leaves}
+            ]
+        }}"""
+
+
+cases = ",\n".join(case_code(case) for case in vectors["cases"])
+cairo_code = f"""// Generated by test_vectors/generate_cairo.py from {os.path.basename(vectors_path)}, do not edit.
+
+pub const TEST_VECTORS_VERSION: u32 = {vectors['version']};
+
+#[derive(Drop)]
+pub struct VectorLeaf {{
+    pub claimee: felt252,
+    pub amount: u128,
+    pub timestamp: u128,
+    pub id: u128,
+    pub leaf: felt252,
+    pub proof: Array<felt252>
+}}
+
+#[derive(Drop)]
+pub struct TestVector {{
+    pub name: felt252,
+    pub root: felt252,
+    pub leaves: Array<VectorLeaf>
+}}
+
+pub fn test_vectors() -> Array<TestVector> {{
+    array![
+{cases}
+    ]
+}}
+"""
+
+try:
+    with open(output_path, "w") as output_file:
+        output_file.write(cairo_code)
+except Exception as e:
+    print(e)
+    raise SystemExit(1)
+
+print(f"Wrote {len(vectors['cases'])} test vectors to {output_path}")